@@ -14,6 +14,7 @@
 
 pub mod vonkries;
 
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub enum AdaptationTransform {
     XYZScaling,
     VonKries,
@@ -28,13 +29,18 @@ pub enum AdaptationTransform {
     BiancoSchettiniPC,
 }
 
+/// Chromatic adaptation transform applied implicitly by the conversions that take a `white`
+/// illuminant but are defined for another one, e.g. the *CIE Illuminant D65* based uniform colour
+/// spaces, and by the gamut computations.
+pub const DEFAULT_TRANSFORM: AdaptationTransform = AdaptationTransform::Bradford;
+
 impl AdaptationTransform {
     pub fn matrix(&self) -> [f64; 9] {
         match &self {
             AdaptationTransform::XYZScaling => [
-                3.0, 3.0, 3.0,
-                3.0, 3.0, 3.0,
-                3.0, 3.0, 3.0,
+                1.0, 0.0, 0.0,
+                0.0, 1.0, 0.0,
+                0.0, 0.0, 1.0,
             ],
             AdaptationTransform::VonKries => [
                 0.4002400, 0.7076000, -0.0808100,
@@ -13,7 +13,45 @@
 // limitations under the License.
 
 use crate::adaptation::AdaptationTransform;
+use crate::models::cie_xyz::Xyz;
 
-pub fn chromatic_adaptation_matrix(transform: &AdaptationTransform) {// (XYZ_w, XYZ_wr, transform='CAT02')
-    unimplemented!()
+use cgmath::*;
+
+/// Computes the *chromatic adaptation* matrix from test viewing conditions to reference viewing
+/// conditions using the *Von Kries* method.
+///
+/// # Arguments
+///
+/// * `xyz_w` - *CIE XYZ* tristimulus values of the test illuminant white.
+/// * `xyz_wr` - *CIE XYZ* tristimulus values of the reference illuminant white.
+/// * `transform` - chromatic adaptation transform.
+///
+/// # Returns
+///
+/// Chromatic adaptation matrix, to be applied to *CIE XYZ* column vectors.
+///
+/// # Example
+///
+/// ```
+/// use cgmath::Vector3;
+/// use pigments::adaptation::AdaptationTransform;
+/// use pigments::adaptation::vonkries::chromatic_adaptation_matrix;
+/// use pigments::models::cie_xyz::Xyz;
+///
+/// let d65 = Xyz{x: 0.95047, y: 1.0, z: 1.08883};
+/// let d50 = Xyz{x: 0.96422, y: 1.0, z: 0.82521};
+/// let m = chromatic_adaptation_matrix(&d65, &d50, &AdaptationTransform::Bradford);
+/// let w = m * Vector3{x: d65.x, y: d65.y, z: d65.z};
+/// assert!((w.x - d50.x).abs() < 1e-12 && (w.y - d50.y).abs() < 1e-12 && (w.z - d50.z).abs() < 1e-12);
+/// ```
+pub fn chromatic_adaptation_matrix(xyz_w: &Xyz, xyz_wr: &Xyz, transform: &AdaptationTransform) -> Matrix3<f64> {
+    let m = transform.matrix();
+    let m = Matrix3::new(m[0], m[3], m[6], m[1], m[4], m[7], m[2], m[5], m[8]);
+    let m_inv = m.invert().expect("chromatic adaptation transforms are invertible");
+
+    let rgb_w = m * Vector3{x: xyz_w.x, y: xyz_w.y, z: xyz_w.z};
+    let rgb_wr = m * Vector3{x: xyz_wr.x, y: xyz_wr.y, z: xyz_wr.z};
+    let d = Matrix3::from_diagonal(Vector3{x: rgb_wr.x / rgb_w.x, y: rgb_wr.y / rgb_w.y, z: rgb_wr.z / rgb_w.z});
+
+    m_inv * d * m
 }
@@ -31,6 +31,10 @@ use cgmath::Vector2;
 ///    FL11, FL12, FL3.1, FL3.10, FL3.11, FL3.12, FL3.13, FL3.14, FL3.15, FL3.2,
 ///    FL3.3, FL3.4, FL3.5, FL3.6, FL3.7, FL3.8, FL3.9)
 ///  - High Pressure Discharge Lamps (HP1, HP2, HP3, HP4, HP5)
+///
+/// The *DCI-P3* theatrical white point and the *ACES* white point are also available; as they are
+/// defined by their chromaticity coordinates rather than by spectral distributions, both observers
+/// share the same values.
 pub fn illuminant(o: StandardObserver, i: Illuminant) -> Vector2<f64> {
     match o {
         StandardObserver::Two => {
@@ -76,6 +80,8 @@ pub fn illuminant(o: StandardObserver, i: Illuminant) -> Vector2<f64> {
                 Illuminant::HP3 =>      { Vector2{x: 0.43020, y: 0.40750} },
                 Illuminant::HP4 =>      { Vector2{x: 0.38120, y: 0.37970} },
                 Illuminant::HP5 =>      { Vector2{x: 0.37760, y: 0.37130} },
+                Illuminant::DCIP3 =>    { Vector2{x: 0.31400, y: 0.35100} },
+                Illuminant::ACES =>     { Vector2{x: 0.32168, y: 0.33767} },
             }
         },
         StandardObserver::Ten => {
@@ -121,6 +127,8 @@ pub fn illuminant(o: StandardObserver, i: Illuminant) -> Vector2<f64> {
                 Illuminant::HP3 =>      { Vector2{x: 0.435560034503954, y: 0.398801084399711} },
                 Illuminant::HP4 =>      { Vector2{x: 0.385193641123543, y: 0.368275479241015} },
                 Illuminant::HP5 =>      { Vector2{x: 0.380316415606638, y: 0.366617114797851} },
+                Illuminant::DCIP3 =>    { Vector2{x: 0.31400, y: 0.35100} },
+                Illuminant::ACES =>     { Vector2{x: 0.32168, y: 0.33767} },
             }
         },
     }
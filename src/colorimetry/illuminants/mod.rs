@@ -65,4 +65,6 @@ pub enum Illuminant {
     HP3,
     HP4,
     HP5,
+    DCIP3,
+    ACES,
 }
@@ -18,8 +18,74 @@
 //! [`CIEce` : CIE. (2004). CIE 15:2004 Tables Data.](https://law.resource.org/pub/us/cfr/ibr/003/cie.15.2004.tables.xls)
 //! [`CIEcf` : CIE. (n.d.). CIE Spectral Data.](http://files.cie.co.at/204.xls)
 
+use super::Illuminant;
 use crate::Spectrum;
 
+/// Returns the relative spectral power distribution of an *illuminant*, sampled every 5nm.
+///
+/// Returns `None` for the illuminants defined only by their chromaticity coordinates, *DCI-P3*
+/// and *ACES*.
+///
+/// # Example
+///
+/// ```
+/// use pigments::colorimetry::illuminants::spectrum;
+/// use pigments::colorimetry::illuminants::Illuminant;
+///
+/// assert!(spectrum::illuminant(Illuminant::D65).is_some());
+/// assert!(spectrum::illuminant(Illuminant::DCIP3).is_none());
+/// ```
+pub fn illuminant(i: Illuminant) -> Option<Spectrum<&'static [f64]>> {
+    match i {
+        Illuminant::A => Some(sds(&SDS_A)),
+        Illuminant::B => Some(sds(&SDS_B)),
+        Illuminant::C => Some(sds(&SDS_C)),
+        Illuminant::D50 => Some(sds(&SDS_D50)),
+        Illuminant::D55 => Some(sds(&SDS_D55)),
+        Illuminant::D60 => Some(sds(&SDS_D60)),
+        Illuminant::D65 => Some(sds(&SDS_D65)),
+        Illuminant::D75 => Some(sds(&SDS_D75)),
+        Illuminant::E => Some(sds(&SDS_E)),
+        Illuminant::FL1 => Some(sds(&SDS_FL1)),
+        Illuminant::FL2 => Some(sds(&SDS_FL2)),
+        Illuminant::FL3 => Some(sds(&SDS_FL3)),
+        Illuminant::FL4 => Some(sds(&SDS_FL4)),
+        Illuminant::FL5 => Some(sds(&SDS_FL5)),
+        Illuminant::FL6 => Some(sds(&SDS_FL6)),
+        Illuminant::FL7 => Some(sds(&SDS_FL7)),
+        Illuminant::FL8 => Some(sds(&SDS_FL8)),
+        Illuminant::FL9 => Some(sds(&SDS_FL9)),
+        Illuminant::FL10 => Some(sds(&SDS_FL10)),
+        Illuminant::FL11 => Some(sds(&SDS_FL11)),
+        Illuminant::FL12 => Some(sds(&SDS_FL12)),
+        Illuminant::FL31 => Some(sds(&SDS_FL3_1)),
+        Illuminant::FL32 => Some(sds(&SDS_FL3_2)),
+        Illuminant::FL33 => Some(sds(&SDS_FL3_3)),
+        Illuminant::FL34 => Some(sds(&SDS_FL3_4)),
+        Illuminant::FL35 => Some(sds(&SDS_FL3_5)),
+        Illuminant::FL36 => Some(sds(&SDS_FL3_6)),
+        Illuminant::FL37 => Some(sds(&SDS_FL3_7)),
+        Illuminant::FL38 => Some(sds(&SDS_FL3_8)),
+        Illuminant::FL39 => Some(sds(&SDS_FL3_9)),
+        Illuminant::FL310 => Some(sds(&SDS_FL3_10)),
+        Illuminant::FL311 => Some(sds(&SDS_FL3_11)),
+        Illuminant::FL312 => Some(sds(&SDS_FL3_12)),
+        Illuminant::FL313 => Some(sds(&SDS_FL3_13)),
+        Illuminant::FL314 => Some(sds(&SDS_FL3_14)),
+        Illuminant::FL315 => Some(sds(&SDS_FL3_15)),
+        Illuminant::HP1 => Some(sds(&SDS_HP1)),
+        Illuminant::HP2 => Some(sds(&SDS_HP2)),
+        Illuminant::HP3 => Some(sds(&SDS_HP3)),
+        Illuminant::HP4 => Some(sds(&SDS_HP4)),
+        Illuminant::HP5 => Some(sds(&SDS_HP5)),
+        Illuminant::DCIP3 | Illuminant::ACES => None,
+    }
+}
+
+fn sds<const N: usize>(s: &'static Spectrum<[f64; N]>) -> Spectrum<&'static [f64]> {
+    Spectrum{start: s.start, interval: s.interval, values: &s.values}
+}
+
 const SDS_A: Spectrum<[f64; 97]> = Spectrum {
     start: 300,
     interval: 5,
@@ -0,0 +1,172 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Gamut boundary
+//!
+//! Boundary queries of *RGB* colour space gamuts, in uniform colour spaces and chromaticity
+//! diagrams.
+
+use super::in_gamut;
+use crate::adaptation::DEFAULT_TRANSFORM;
+use crate::colorimetry::illuminants::Illuminant;
+use crate::models::cie_lch::LchAb;
+use crate::models::oklab::Oklch;
+use crate::models::rgb::color_space::ColorSpace;
+use crate::models::xy_to_uv_prime;
+use crate::models::rgb::LinearRgb;
+
+use cgmath::*;
+
+/// Number of steps used to bracket the boundary before refining it with a binary search.
+const STEPS: usize = 100;
+
+/// Chroma precision of the binary search, relative to the step of the chroma walk that bracketed
+/// the boundary.
+const EPSILON: f64 = 1e-7;
+
+/// Returns the largest chroma for which `rgb_at` is inside the gamut, walking along the chroma axis
+/// from the achromatic colour. The walk starts over `[0, range]` and the range is doubled until the
+/// gamut is left, so `range` only sets the initial step size.
+fn max_chroma(range: f64, rgb_at: impl Fn(f64) -> LinearRgb) -> f64 {
+    if !in_gamut(&rgb_at(0.0)) {
+        return 0.0;
+    }
+
+    let (mut min, mut max) = (0.0, range);
+    let mut step = range / STEPS as f64;
+    let upper = 'search: loop {
+        let start = min;
+        for i in 1..=STEPS {
+            let chroma = start + step * i as f64;
+            if in_gamut(&rgb_at(chroma)) {
+                min = chroma;
+            } else {
+                break 'search chroma;
+            }
+        }
+
+        if !max.is_finite() {
+            return min;
+        }
+        step = max / STEPS as f64;
+        max *= 2.0;
+    };
+
+    let mut max = upper;
+    while max - min > EPSILON * step {
+        let chroma = (min + max) / 2.0;
+        if in_gamut(&rgb_at(chroma)) {
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+
+    min
+}
+
+/// Returns the maximum *CIE LCh(ab)* chroma reachable inside the gamut of an *RGB* colour space at
+/// the given lightness and hue, *CIE L\*a\*b\** being relative to the colour space whitepoint.
+///
+/// # Arguments
+///
+/// * `space` - *RGB* colour space.
+/// * `l` - *CIE L\** lightness, in [0, 100].
+/// * `h` - hue angle, in degrees.
+///
+/// # Example
+///
+/// ```
+/// use pigments::gamut::boundary::max_chroma_lab;
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// // The sRGB red primary lies on the boundary.
+/// assert!((max_chroma_lab(ColorSpace::Srgb, 53.2408, 39.9990) - 104.5518).abs() < 5e-2);
+/// assert_eq!(max_chroma_lab(ColorSpace::Srgb, 100.0, 39.9990), 0.0);
+///
+/// // Wide gamuts reach chromas far beyond the ones of real surface colours.
+/// assert!((max_chroma_lab(ColorSpace::Aces2065_1, 5.0, 320.0) - 254.8168).abs() < 5e-2);
+/// ```
+pub fn max_chroma_lab(space: ColorSpace, l: f64, h: f64) -> f64 {
+    let white = space.whitepoint();
+
+    max_chroma(250.0, |c| {
+        let lab = LchAb{l, c, h}.to_lab();
+
+        lab.to_xyz(white.clone()).to_linear_rgb(white.clone(), space, None)
+    })
+}
+
+/// Returns the maximum *Oklch* chroma reachable inside the gamut of an *RGB* colour space at the
+/// given lightness and hue, the colour space whitepoint being adapted to *CIE Illuminant D65*.
+///
+/// # Arguments
+///
+/// * `space` - *RGB* colour space.
+/// * `l` - *Oklab* lightness, in [0, 1].
+/// * `h` - hue angle, in degrees.
+///
+/// # Example
+///
+/// ```
+/// use pigments::gamut::boundary::max_chroma_oklch;
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// // The sRGB red primary lies on the boundary.
+/// assert!((max_chroma_oklch(ColorSpace::Srgb, 0.6279554, 29.2338851) - 0.2576833).abs() < 5e-4);
+/// assert!(max_chroma_oklch(ColorSpace::DisplayP3, 0.6279554, 29.2338851) > 0.2576833);
+/// assert!((max_chroma_oklch(ColorSpace::Aces2065_1, 0.5, 320.0) - 0.5008258).abs() < 5e-4);
+/// ```
+pub fn max_chroma_oklch(space: ColorSpace, l: f64, h: f64) -> f64 {
+    max_chroma(0.5, |c| {
+        Oklch{l, c, h}
+            .to_oklab()
+            .to_xyz(Illuminant::D65)
+            .to_linear_rgb(Illuminant::D65, space, Some(DEFAULT_TRANSFORM))
+    })
+}
+
+/// Returns the gamut boundary of an *RGB* colour space in the *CIE xy* chromaticity diagram, the
+/// triangle of its red, green and blue primaries.
+///
+/// # Example
+///
+/// ```
+/// use cgmath::Vector2;
+/// use pigments::gamut::boundary::boundary_xy;
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// assert_eq!(boundary_xy(ColorSpace::Srgb)[0], Vector2{x: 0.64, y: 0.33});
+/// ```
+pub fn boundary_xy(space: ColorSpace) -> [Vector2<f64>; 3] {
+    space.primaries()
+}
+
+/// Returns the gamut boundary of an *RGB* colour space in the *CIE 1976 UCS* u'v' chromaticity
+/// diagram, the triangle of its red, green and blue primaries.
+///
+/// # Example
+///
+/// ```
+/// use pigments::gamut::boundary::boundary_uv;
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// let red = boundary_uv(ColorSpace::Srgb)[0];
+/// assert!((red.x - 0.4507042).abs() < 1e-7 && (red.y - 0.5228873).abs() < 1e-7);
+/// ```
+pub fn boundary_uv(space: ColorSpace) -> [Vector2<f64>; 3] {
+    let [r, g, b] = space.primaries();
+
+    [xy_to_uv_prime(&r), xy_to_uv_prime(&g), xy_to_uv_prime(&b)]
+}
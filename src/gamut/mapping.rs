@@ -0,0 +1,262 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Gamut mapping
+//!
+//! Strategies bringing colours that are out of the gamut of a target *RGB* colour space back
+//! inside it.
+//!
+//! ## References
+//!
+//! [`CSS Color Module Level 4` : 13.2. CSS Gamut Mapping to an RGB Destination.](https://www.w3.org/TR/css-color-4/#css-gamut-mapping)
+//! [`ACES` : ACES Reference Gamut Compression User Guide.](https://docs.acescentral.com/guides/rgc-user/)
+
+use super::{clip, in_gamut};
+use crate::adaptation::{AdaptationTransform, DEFAULT_TRANSFORM};
+use crate::colorimetry::illuminants::Illuminant;
+use crate::models::cie_lab::CieLab;
+use crate::models::oklab::Oklab;
+use crate::models::rgb::color_space::ColorSpace;
+use crate::models::rgb::{LinearRgb, Rgb};
+
+use cgmath::*;
+
+/// Just noticeable difference in *Oklab*, used by the *CSS Color 4* algorithm.
+const JND: f64 = 0.02;
+
+/// Chroma precision of the binary searches.
+const EPSILON: f64 = 0.0001;
+
+/// Gamut mapping strategy.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum GamutMapping {
+    /// Clips each linear component independently, which is fast but shifts hue and lightness.
+    Clip,
+    /// Reduces the *Oklch* chroma with a binary search until the clipped colour is within a just
+    /// noticeable difference, as specified by *CSS Color 4*.
+    CssColor4,
+    /// Compresses the distance of each component to the achromatic axis with the *ACES 1.3*
+    /// reference gamut compression, performed in *ACEScg* which its parameters are calibrated for,
+    /// then clips the remaining excursions in the target colour space.
+    AcesReferenceGamutCompression,
+    /// Projects the colour towards the lightness axis in *CIE LCh(ab)*, at constant lightness and
+    /// hue, until it is inside the gamut.
+    LchProjection,
+}
+
+/// Converts *RGB* values to another *RGB* colour space and maps them inside its gamut.
+///
+/// # Arguments
+///
+/// * `rgb` - *RGB* values to map.
+/// * `space` - target *RGB* colour space.
+/// * `adaptation` - chromatic adaptation transform applied when the whitepoints differ.
+/// * `method` - gamut mapping strategy.
+///
+/// # Returns
+///
+/// *RGB* values inside the gamut of the target colour space.
+///
+/// # Example
+///
+/// ```
+/// use pigments::adaptation::AdaptationTransform;
+/// use pigments::gamut::mapping::{map, GamutMapping};
+/// use pigments::models::rgb::color_space::ColorSpace;
+/// use pigments::models::rgb::Rgb;
+///
+/// let red = Rgb{r: 1.0, g: 0.0, b: 0.0, space: ColorSpace::DisplayP3};
+/// for method in &[
+///     GamutMapping::Clip,
+///     GamutMapping::CssColor4,
+///     GamutMapping::AcesReferenceGamutCompression,
+///     GamutMapping::LchProjection,
+/// ] {
+///     let srgb = map(&red, ColorSpace::Srgb, None, *method);
+///     assert!([srgb.r, srgb.g, srgb.b].iter().all(|v| (0.0..=1.0).contains(v)));
+///     assert!(srgb.r > 0.9 && srgb.g < 0.3 && srgb.b < 0.3);
+/// }
+///
+/// let grey = Rgb{r: 0.5, g: 0.5, b: 0.5, space: ColorSpace::DisplayP3};
+/// let srgb = map(&grey, ColorSpace::Srgb, None, GamutMapping::CssColor4);
+/// assert!((srgb.r - 0.5).abs() < 1e-9 && (srgb.g - 0.5).abs() < 1e-9 && (srgb.b - 0.5).abs() < 1e-9);
+///
+/// // Inside the ACEScg gamut, well within the compression thresholds, but outside of the sRGB one:
+/// // the reference gamut compression leaves it untouched and only the clipping applies.
+/// let green = Rgb{r: 0.1, g: 0.5, b: 0.1, space: ColorSpace::AcesCg};
+/// let adaptation = Some(AdaptationTransform::Bradford);
+/// assert!(green.to_rgb(ColorSpace::Srgb, adaptation).r < 0.0);
+/// let compressed = map(&green, ColorSpace::Srgb, adaptation, GamutMapping::AcesReferenceGamutCompression);
+/// let clipped = map(&green, ColorSpace::Srgb, adaptation, GamutMapping::Clip);
+/// assert!((compressed.g - clipped.g).abs() < 1e-9 && (compressed.b - clipped.b).abs() < 1e-9);
+/// ```
+pub fn map<S: Into<ColorSpace> + Copy>(
+    rgb: &Rgb<S>,
+    space: ColorSpace,
+    adaptation: Option<AdaptationTransform>,
+    method: GamutMapping,
+) -> Rgb {
+    let linear = rgb.linearize().to_linear_rgb(space, adaptation);
+    let mapped = match method {
+        GamutMapping::Clip => clip(&linear),
+        GamutMapping::CssColor4 => css_color_4(&linear),
+        GamutMapping::AcesReferenceGamutCompression => aces_reference_gamut_compression(&linear),
+        GamutMapping::LchProjection => lch_projection(&linear),
+    };
+
+    mapped.encode()
+}
+
+fn white(space: ColorSpace) -> LinearRgb {
+    LinearRgb{r: 1.0, g: 1.0, b: 1.0, space}
+}
+
+fn black(space: ColorSpace) -> LinearRgb {
+    LinearRgb{r: 0.0, g: 0.0, b: 0.0, space}
+}
+
+fn to_oklab(rgb: &LinearRgb) -> Vector3<f64> {
+    let lab = Oklab::from_xyz(&rgb.to_xyz(Some(DEFAULT_TRANSFORM), Illuminant::D65), Illuminant::D65);
+
+    Vector3{x: lab.l, y: lab.a, z: lab.b}
+}
+
+fn from_oklch(l: f64, c: f64, h: f64, space: ColorSpace) -> LinearRgb {
+    let xyz = Oklab{l, a: c * h.cos(), b: c * h.sin()}.to_xyz(Illuminant::D65);
+
+    xyz.to_linear_rgb(Illuminant::D65, space, Some(DEFAULT_TRANSFORM))
+}
+
+/// *CSS Color 4* binary search of the *Oklch* chroma.
+fn css_color_4(rgb: &LinearRgb) -> LinearRgb {
+    let lab = to_oklab(rgb);
+    let (l, c, h) = (lab.x, lab.y.hypot(lab.z), lab.z.atan2(lab.y));
+
+    if l >= 1.0 {
+        return white(rgb.space);
+    }
+    if l <= 0.0 {
+        return black(rgb.space);
+    }
+    if in_gamut(rgb) {
+        return *rgb;
+    }
+
+    let delta_e = |a: &LinearRgb, b: &LinearRgb| to_oklab(a).distance(to_oklab(b));
+
+    let mut current = *rgb;
+    let mut clipped = clip(&current);
+    if delta_e(&clipped, &current) < JND {
+        return clipped;
+    }
+
+    let (mut min, mut max) = (0.0, c);
+    let mut min_in_gamut = true;
+    while max - min > EPSILON {
+        let chroma = (min + max) / 2.0;
+        current = from_oklch(l, chroma, h, rgb.space);
+
+        if min_in_gamut && in_gamut(&current) {
+            min = chroma;
+            continue;
+        }
+
+        clipped = clip(&current);
+        let e = delta_e(&clipped, &current);
+        if e < JND {
+            if JND - e < EPSILON {
+                return clipped;
+            }
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+
+    clipped
+}
+
+const RGC_THRESHOLD: [f64; 3] = [0.815, 0.803, 0.880];
+const RGC_LIMIT: [f64; 3] = [1.147, 1.264, 1.312];
+const RGC_POWER: f64 = 1.2;
+
+/// *ACES 1.3* reference gamut compression of a distance to the achromatic axis.
+fn compress(distance: f64, threshold: f64, limit: f64) -> f64 {
+    if distance < threshold {
+        return distance;
+    }
+
+    let scale = (limit - threshold)
+        / (((1.0 - threshold) / (limit - threshold)).powf(-RGC_POWER) - 1.0).powf(1.0 / RGC_POWER);
+    let d = (distance - threshold) / scale;
+
+    threshold + scale * d / (1.0 + d.powf(RGC_POWER)).powf(1.0 / RGC_POWER)
+}
+
+/// *ACES 1.3* reference gamut compression in *ACEScg*, followed by clipping.
+fn aces_reference_gamut_compression(rgb: &LinearRgb) -> LinearRgb {
+    let acescg = rgb.to_linear_rgb(ColorSpace::AcesCg, Some(DEFAULT_TRANSFORM));
+    let values = [acescg.r, acescg.g, acescg.b];
+    let achromatic = values.iter().cloned().fold(f64::MIN, f64::max);
+    if achromatic == 0.0 {
+        return clip(rgb);
+    }
+
+    let mut compressed = [0.0; 3];
+    for (i, v) in values.iter().enumerate() {
+        let distance = (achromatic - v) / achromatic.abs();
+        compressed[i] = achromatic - compress(distance, RGC_THRESHOLD[i], RGC_LIMIT[i]) * achromatic.abs();
+    }
+
+    let compressed = LinearRgb{r: compressed[0], g: compressed[1], b: compressed[2], space: ColorSpace::AcesCg};
+
+    clip(&compressed.to_linear_rgb(rgb.space, Some(DEFAULT_TRANSFORM)))
+}
+
+/// Binary search of the *CIE LCh(ab)* chroma at constant lightness and hue.
+fn lch_projection(rgb: &LinearRgb) -> LinearRgb {
+    if in_gamut(rgb) {
+        return *rgb;
+    }
+
+    let white_point = rgb.space.whitepoint();
+    let lab = CieLab::from_xyz(&rgb.to_xyz(None, white_point.clone()), white_point.clone());
+    let (l, c, h) = (lab.l, lab.a.hypot(lab.b), lab.b.atan2(lab.a));
+
+    if l >= 100.0 {
+        return white(rgb.space);
+    }
+    if l <= 0.0 {
+        return black(rgb.space);
+    }
+
+    let at_chroma = |chroma: f64| {
+        CieLab{l, a: chroma * h.cos(), b: chroma * h.sin()}
+            .to_xyz(white_point.clone())
+            .to_linear_rgb(white_point.clone(), rgb.space, None)
+    };
+
+    let (mut min, mut max) = (0.0, c);
+    while max - min > EPSILON {
+        let chroma = (min + max) / 2.0;
+        if in_gamut(&at_chroma(chroma)) {
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+
+    clip(&at_chroma(min))
+}
@@ -0,0 +1,235 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Gamut metrics
+//!
+//! Areas, volumes and coverages of *RGB* colour space gamuts.
+//!
+//! Coverages are expressed as the percentage of a reference gamut that is also inside the
+//! evaluated gamut, e.g. a display covering 96% of *DCI-P3*.
+
+use super::boundary::{boundary_uv, boundary_xy};
+use crate::models::cie_lab::CieLab;
+use crate::models::rgb::color_space::ColorSpace;
+use crate::models::rgb::LinearRgb;
+
+use cgmath::*;
+
+/// Number of subdivisions of each edge of the *RGB* cube when tessellating the gamut boundary.
+const SUBDIVISIONS: usize = 32;
+
+/// Returns the area of a simple polygon.
+///
+/// # Example
+///
+/// ```
+/// use cgmath::Vector2;
+/// use pigments::gamut::metrics::polygon_area;
+///
+/// let square = [
+///     Vector2{x: 0.0, y: 0.0},
+///     Vector2{x: 0.0, y: 2.0},
+///     Vector2{x: 2.0, y: 2.0},
+///     Vector2{x: 2.0, y: 0.0},
+/// ];
+/// assert_eq!(polygon_area(&square), 4.0);
+/// ```
+pub fn polygon_area(polygon: &[Vector2<f64>]) -> f64 {
+    signed_area(polygon).abs()
+}
+
+/// Returns the percentage of the area of a `reference` polygon covered by a convex `gamut`
+/// polygon.
+///
+/// The reference polygon doesn't need to be convex, e.g. it can be *Pointer's gamut*.
+///
+/// # Example
+///
+/// ```
+/// use pigments::gamut::boundary::boundary_xy;
+/// use pigments::gamut::metrics::polygon_coverage;
+/// use pigments::gamut::reference::POINTER_GAMUT;
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// assert!(polygon_coverage(&boundary_xy(ColorSpace::Bt2020), &POINTER_GAMUT) > 99.0);
+/// ```
+pub fn polygon_coverage(gamut: &[Vector2<f64>], reference: &[Vector2<f64>]) -> f64 {
+    let area = polygon_area(reference);
+    if area == 0.0 {
+        return 0.0;
+    }
+
+    100.0 * polygon_area(&clip_polygon(reference, gamut)) / area
+}
+
+/// Returns the area of the gamut of an *RGB* colour space in the *CIE xy* chromaticity diagram.
+///
+/// # Example
+///
+/// ```
+/// use pigments::gamut::metrics::area_xy;
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// assert!((area_xy(ColorSpace::Srgb) - 0.11205).abs() < 1e-9);
+/// ```
+pub fn area_xy(space: ColorSpace) -> f64 {
+    polygon_area(&boundary_xy(space))
+}
+
+/// Returns the area of the gamut of an *RGB* colour space in the *CIE 1976 UCS* u'v' chromaticity
+/// diagram.
+///
+/// # Example
+///
+/// ```
+/// use pigments::gamut::metrics::area_uv;
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// assert!(area_uv(ColorSpace::Bt2020) > area_uv(ColorSpace::DciP3));
+/// ```
+pub fn area_uv(space: ColorSpace) -> f64 {
+    polygon_area(&boundary_uv(space))
+}
+
+/// Returns the volume of the gamut of an *RGB* colour space in *CIE L\*a\*b\**, relative to the
+/// colour space whitepoint.
+///
+/// The surface of the *RGB* cube is tessellated into triangles which are converted to
+/// *CIE L\*a\*b\**, the volume they enclose is then computed with the divergence theorem.
+///
+/// # Example
+///
+/// ```
+/// use pigments::gamut::metrics::volume_lab;
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// let volume = volume_lab(ColorSpace::Srgb);
+/// assert!(volume > 8.0e5 && volume < 8.5e5);
+/// assert!(volume_lab(ColorSpace::DisplayP3) > volume);
+/// ```
+pub fn volume_lab(space: ColorSpace) -> f64 {
+    let white = space.whitepoint();
+    let n = SUBDIVISIONS;
+    let lab = |rgb: [f64; 3]| {
+        let rgb = LinearRgb{r: rgb[0], g: rgb[1], b: rgb[2], space};
+
+        let lab = CieLab::from_xyz(&rgb.to_xyz(None, white.clone()), white.clone());
+
+        Vector3{x: lab.l, y: lab.a, z: lab.b}
+    };
+
+    let mut volume = 0.0;
+    for axis in 0..3 {
+        let (i, j) = ((axis + 1) % 3, (axis + 2) % 3);
+        for &side in &[0.0, 1.0] {
+            // Vertices of the face, oriented so that every triangle normal points outwards.
+            let vertex = |u: usize, v: usize| {
+                let mut rgb = [0.0; 3];
+                rgb[axis] = side;
+                rgb[i] = u as f64 / n as f64;
+                rgb[j] = v as f64 / n as f64;
+
+                lab(rgb)
+            };
+            let grid: Vec<Vec<Vector3<f64>>> = (0..=n)
+                .map(|u| (0..=n).map(|v| vertex(u, v)).collect())
+                .collect();
+
+            for u in 0..n {
+                for v in 0..n {
+                    let (p00, p10) = (grid[u][v], grid[u + 1][v]);
+                    let (p11, p01) = (grid[u + 1][v + 1], grid[u][v + 1]);
+                    let tetrahedra = p00.dot(p10.cross(p11)) + p00.dot(p11.cross(p01));
+                    volume += if side == 1.0 { tetrahedra } else { -tetrahedra };
+                }
+            }
+        }
+    }
+
+    (volume / 6.0).abs()
+}
+
+/// Returns the percentage of the gamut of a `reference` *RGB* colour space covered by the gamut of
+/// an *RGB* colour space, in the *CIE xy* chromaticity diagram.
+///
+/// # Example
+///
+/// ```
+/// use pigments::gamut::metrics::coverage_xy;
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// assert!((coverage_xy(ColorSpace::DisplayP3, ColorSpace::Srgb) - 100.0).abs() < 1e-9);
+/// assert!(coverage_xy(ColorSpace::Srgb, ColorSpace::DciP3) < 80.0);
+/// ```
+pub fn coverage_xy(space: ColorSpace, reference: ColorSpace) -> f64 {
+    polygon_coverage(&boundary_xy(space), &boundary_xy(reference))
+}
+
+/// Returns the percentage of the gamut of a `reference` *RGB* colour space covered by the gamut of
+/// an *RGB* colour space, in the *CIE 1976 UCS* u'v' chromaticity diagram.
+///
+/// # Example
+///
+/// ```
+/// use pigments::gamut::metrics::coverage_uv;
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// assert!(coverage_uv(ColorSpace::DciP3, ColorSpace::Bt2020) < 90.0);
+/// ```
+pub fn coverage_uv(space: ColorSpace, reference: ColorSpace) -> f64 {
+    polygon_coverage(&boundary_uv(space), &boundary_uv(reference))
+}
+
+/// Returns the signed area of a polygon, positive when its vertices are counter-clockwise.
+fn signed_area(polygon: &[Vector2<f64>]) -> f64 {
+    let n = polygon.len();
+
+    (0..n).map(|i| cross(polygon[i], polygon[(i + 1) % n])).sum::<f64>() / 2.0
+}
+
+fn cross(a: Vector2<f64>, b: Vector2<f64>) -> f64 {
+    a.x * b.y - a.y * b.x
+}
+
+/// Clips a polygon against a convex polygon with the *Sutherland-Hodgman* algorithm.
+fn clip_polygon(subject: &[Vector2<f64>], convex: &[Vector2<f64>]) -> Vec<Vector2<f64>> {
+    let orientation = signed_area(convex).signum();
+    let mut output = subject.to_vec();
+
+    for k in 0..convex.len() {
+        let (a, b) = (convex[k], convex[(k + 1) % convex.len()]);
+        let inside = |p: Vector2<f64>| orientation * cross(b - a, p - a) >= 0.0;
+        let intersection = |p: Vector2<f64>, q: Vector2<f64>| {
+            let t = cross(b - a, p - a) / cross(b - a, p - q);
+
+            p + (q - p) * t
+        };
+
+        let input = std::mem::take(&mut output);
+        for i in 0..input.len() {
+            let (p, q) = (input[i], input[(i + 1) % input.len()]);
+            match (inside(p), inside(q)) {
+                (true, true) => output.push(q),
+                (true, false) => output.push(intersection(p, q)),
+                (false, true) => {
+                    output.push(intersection(p, q));
+                    output.push(q);
+                }
+                (false, false) => {}
+            }
+        }
+    }
+
+    output
+}
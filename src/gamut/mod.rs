@@ -0,0 +1,80 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Gamut
+//!
+//! Gamut membership, boundaries, metrics and mapping of colours to *RGB* colour spaces.
+//!
+//! Gamuts are display-referred: a colour is inside the gamut of an *RGB* colour space when its
+//! linear components are all in the [0, 1] domain.
+
+pub mod boundary;
+pub mod mapping;
+pub mod metrics;
+pub mod reference;
+
+use crate::colorimetry::illuminants::Illuminant;
+use crate::models::cie_xyz::Xyz;
+use crate::adaptation::DEFAULT_TRANSFORM;
+use crate::models::rgb::color_space::ColorSpace;
+use crate::models::rgb::LinearRgb;
+
+/// Returns whether *CIE XYZ* tristimulus values are inside the gamut of an *RGB* colour space.
+///
+/// The tristimulus values are adapted from `white` to the colour space whitepoint with the
+/// [`DEFAULT_TRANSFORM`] when they differ.
+///
+/// # Arguments
+///
+/// * `xyz` - *CIE XYZ* tristimulus values.
+/// * `white` - illuminant the tristimulus values are relative to.
+/// * `space` - *RGB* colour space.
+/// * `tolerance` - allowed excursion of the linear components outside of the [0, 1] domain.
+///
+/// # Example
+///
+/// ```
+/// use pigments::colorimetry::illuminants::Illuminant;
+/// use pigments::gamut::is_in_gamut;
+/// use pigments::models::cie_xyz::Xyz;
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// let srgb_red = Xyz{x: 0.4123908, y: 0.2126390, z: 0.0193308};
+/// assert!(is_in_gamut(&srgb_red, Illuminant::D65, ColorSpace::Srgb, 1e-6));
+///
+/// let p3_green = Xyz{x: 0.2657645, y: 0.6914792, z: 0.0451134};
+/// assert!(!is_in_gamut(&p3_green, Illuminant::D65, ColorSpace::Srgb, 1e-6));
+/// assert!(is_in_gamut(&p3_green, Illuminant::D65, ColorSpace::DisplayP3, 1e-6));
+/// ```
+pub fn is_in_gamut(xyz: &Xyz, white: Illuminant, space: ColorSpace, tolerance: f64) -> bool {
+    let rgb = xyz.to_linear_rgb(white, space, Some(DEFAULT_TRANSFORM));
+
+    [rgb.r, rgb.g, rgb.b].iter().all(|v| (-tolerance..=1.0 + tolerance).contains(v))
+}
+
+/// Returns whether linear *RGB* values are inside the gamut of their colour space.
+fn in_gamut(rgb: &LinearRgb) -> bool {
+    [rgb.r, rgb.g, rgb.b].iter().all(|v| (0.0..=1.0).contains(v))
+}
+
+/// Clips linear *RGB* values to the gamut of their colour space.
+fn clip(rgb: &LinearRgb) -> LinearRgb {
+    LinearRgb{
+        r: rgb.r.clamp(0.0, 1.0),
+        g: rgb.g.clamp(0.0, 1.0),
+        b: rgb.b.clamp(0.0, 1.0),
+        space: rgb.space,
+    }
+}
+
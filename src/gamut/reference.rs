@@ -0,0 +1,159 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Reference gamuts
+//!
+//! Gamuts of real surface colours, used as references when evaluating *RGB* colour spaces.
+//!
+//! ## References
+//!
+//! [`Pointer (1980)` : Pointer, M. R. The Gamut of Real Surface Colours.](https://doi.org/10.1002/col.5080050308)
+//! [`MacAdam (1935)` : MacAdam, D. L. Maximum Visual Efficiency of Colored Materials.](https://doi.org/10.1364/JOSA.25.000361)
+//! [`Wyman et al. (2013)` : Simple Analytic Approximations to the CIE XYZ Color Matching Functions.](http://jcgt.org/published/0002/02/01/)
+
+use crate::colorimetry::illuminants::{spectrum, Illuminant};
+
+use cgmath::*;
+
+/// Illuminant *Pointer's gamut* is defined for.
+pub const POINTER_GAMUT_ILLUMINANT: Illuminant = Illuminant::C;
+
+/// Boundary of *Pointer's gamut* in the *CIE xy* chromaticity diagram, for the
+/// *CIE 1931 2 Degree Standard Observer* and *CIE Illuminant C*.
+pub const POINTER_GAMUT: [Vector2<f64>; 35] = [
+    Vector2{x: 0.659, y: 0.316},
+    Vector2{x: 0.634, y: 0.351},
+    Vector2{x: 0.594, y: 0.391},
+    Vector2{x: 0.557, y: 0.427},
+    Vector2{x: 0.523, y: 0.462},
+    Vector2{x: 0.482, y: 0.491},
+    Vector2{x: 0.444, y: 0.515},
+    Vector2{x: 0.409, y: 0.546},
+    Vector2{x: 0.371, y: 0.558},
+    Vector2{x: 0.332, y: 0.573},
+    Vector2{x: 0.288, y: 0.584},
+    Vector2{x: 0.242, y: 0.576},
+    Vector2{x: 0.202, y: 0.530},
+    Vector2{x: 0.177, y: 0.454},
+    Vector2{x: 0.151, y: 0.389},
+    Vector2{x: 0.151, y: 0.330},
+    Vector2{x: 0.162, y: 0.295},
+    Vector2{x: 0.157, y: 0.266},
+    Vector2{x: 0.159, y: 0.245},
+    Vector2{x: 0.142, y: 0.214},
+    Vector2{x: 0.141, y: 0.195},
+    Vector2{x: 0.129, y: 0.168},
+    Vector2{x: 0.138, y: 0.141},
+    Vector2{x: 0.145, y: 0.129},
+    Vector2{x: 0.145, y: 0.106},
+    Vector2{x: 0.161, y: 0.094},
+    Vector2{x: 0.188, y: 0.084},
+    Vector2{x: 0.252, y: 0.104},
+    Vector2{x: 0.324, y: 0.127},
+    Vector2{x: 0.393, y: 0.165},
+    Vector2{x: 0.451, y: 0.199},
+    Vector2{x: 0.508, y: 0.226},
+    Vector2{x: 0.554, y: 0.246},
+    Vector2{x: 0.593, y: 0.262},
+    Vector2{x: 0.646, y: 0.288},
+];
+
+/// Wavelength range of the *MacAdam* limits computation, in nm, sampled every nm.
+const WAVELENGTHS: (u32, u32) = (380, 780);
+
+/// Returns the boundary of the *MacAdam* optimal colours in the *CIE xy* chromaticity diagram.
+///
+/// Optimal colours are the surface colours reaching the largest chroma for a given luminance
+/// factor: their reflectance is either 0 or 1 with at most two transitions across the spectrum.
+/// The *CIE 1931 2 Degree Standard Observer* is approximated with the multi-lobe fit of
+/// *Wyman et al.*
+///
+/// # Arguments
+///
+/// * `y` - luminance factor, in ]0, 1[.
+/// * `i` - illuminant, it must have a spectral distribution.
+///
+/// # Returns
+///
+/// One point per start wavelength of the reflectance band, `None` when the illuminant has no
+/// spectral distribution.
+///
+/// # Example
+///
+/// ```
+/// use pigments::colorimetry::illuminants::Illuminant;
+/// use pigments::gamut::metrics::{area_xy, polygon_area};
+/// use pigments::gamut::reference::macadam_limits;
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// let limits = macadam_limits(0.2, Illuminant::D65).unwrap();
+/// assert!(limits.iter().all(|xy| xy.x > 0.0 && xy.y > 0.0 && xy.x + xy.y < 1.0));
+/// assert!(polygon_area(&limits) > area_xy(ColorSpace::Srgb));
+/// assert!(macadam_limits(0.2, Illuminant::DCIP3).is_none());
+/// ```
+pub fn macadam_limits(y: f64, i: Illuminant) -> Option<Vec<Vector2<f64>>> {
+    let spd = spectrum::illuminant(i)?;
+    let power = |wavelength: u32| {
+        let offset = (wavelength as f64 - spd.start as f64) / spd.interval as f64;
+        let index = offset.floor();
+        if index < 0.0 || index as usize + 1 >= spd.values.len() {
+            return spd.values.get(index as usize).copied().unwrap_or(0.0);
+        }
+        let (a, b) = (spd.values[index as usize], spd.values[index as usize + 1]);
+
+        a + (b - a) * (offset - index)
+    };
+
+    let weights: Vec<Vector3<f64>> = (WAVELENGTHS.0..=WAVELENGTHS.1)
+        .map(|w| cmf(w as f64) * power(w))
+        .collect();
+    let white: Vector3<f64> = weights.iter().sum();
+    let weights: Vec<Vector3<f64>> = weights.iter().map(|w| w / white.y).collect();
+    let n = weights.len();
+
+    let limits = (0..n)
+        .map(|start| {
+            let mut xyz = Vector3::zero();
+            for k in 0..n {
+                let w = weights[(start + k) % n];
+                if xyz.y + w.y >= y {
+                    xyz += w * ((y - xyz.y) / w.y);
+                    break;
+                }
+                xyz += w;
+            }
+            let sum = xyz.x + xyz.y + xyz.z;
+
+            Vector2{x: xyz.x / sum, y: xyz.y / sum}
+        })
+        .collect();
+
+    Some(limits)
+}
+
+/// Returns the *CIE 1931 2 Degree Standard Observer* colour matching functions at a wavelength,
+/// approximated with the multi-lobe piecewise gaussian fit of *Wyman et al.*
+fn cmf(wavelength: f64) -> Vector3<f64> {
+    let g = |mu: f64, sigma_1: f64, sigma_2: f64| {
+        let t = (wavelength - mu) / if wavelength < mu { sigma_1 } else { sigma_2 };
+
+        (-0.5 * t * t).exp()
+    };
+
+    Vector3{
+        x: 1.056 * g(599.8, 37.9, 31.0) + 0.362 * g(442.0, 16.0, 26.7) - 0.065 * g(501.1, 20.4, 26.2),
+        y: 0.821 * g(568.8, 46.9, 40.5) + 0.286 * g(530.9, 16.3, 31.1),
+        z: 1.217 * g(437.0, 11.8, 36.0) + 0.681 * g(459.0, 26.0, 13.8),
+    }
+}
@@ -16,10 +16,12 @@ extern crate cached;
 
 pub mod adaptation;
 pub mod colorimetry;
+pub mod gamut;
 pub mod models;
+pub mod transfer;
 
 pub struct Spectrum<T: ?Sized> {
-    start: u32,
-    interval: u32,
-    values: T
+    pub(crate) start: u32,
+    pub(crate) interval: u32,
+    pub(crate) values: T
 }
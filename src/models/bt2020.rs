@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -12,4 +12,210 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! # ITU-R Recommendation BT.2020
+//! # ITU-R BT.2020 and BT.2100 color encodings
+//!
+//! *ITU-R BT.2020* constant luminance *Y'cC'bcC'rc* encoding and *ITU-R BT.2100* *ICtCp*
+//! encoding, with the *ITU-R BT.2124* ΔE ITP colour difference.
+//!
+//! Both encodings are defined on linear light values with the *BT.2020* primaries, values in other
+//! colour spaces are converted to them first, without chromatic adaptation.
+//!
+//! ## References
+//!
+//! [`ITU-R BT.2020-2` : Parameter values for ultra-high definition television systems.](https://www.itu.int/rec/R-REC-BT.2020)
+//! [`ITU-R BT.2100-2` : Image parameter values for high dynamic range television.](https://www.itu.int/rec/R-REC-BT.2100)
+//! [`ITU-R BT.2124-0` : Objective metric for the assessment of the potential visibility of colour differences in television.](https://www.itu.int/rec/R-REC-BT.2124)
+
+use super::Color;
+use crate::models::rgb::color_space::ColorSpace;
+use crate::models::rgb::LinearRgb;
+
+use cgmath::*;
+
+/// Luminance coefficients of the red and blue components.
+const KR: f64 = 0.2627;
+const KB: f64 = 0.0593;
+
+/// Scale factors of the negative and positive blue and red colour differences.
+const NB: f64 = 1.9404;
+const PB: f64 = 1.5816;
+const NR: f64 = 1.7184;
+const PR: f64 = 0.9936;
+
+const RGB_TO_LMS_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1688.0 / 4096.0, y: 683.0 / 4096.0, z: 99.0 / 4096.0},
+    y: Vector3{x: 2146.0 / 4096.0, y: 2951.0 / 4096.0, z: 309.0 / 4096.0},
+    z: Vector3{x: 262.0 / 4096.0, y: 462.0 / 4096.0, z: 3688.0 / 4096.0}
+};
+
+const LMS_TO_ICTCP_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.5, y: 6610.0 / 4096.0, z: 17933.0 / 4096.0},
+    y: Vector3{x: 0.5, y: -13613.0 / 4096.0, z: -17390.0 / 4096.0},
+    z: Vector3{x: 0.0, y: 7003.0 / 4096.0, z: -543.0 / 4096.0}
+};
+
+const LMS_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 3.4366066943330784, y:-0.7913295555989287, z:-0.025949899690592672},
+    y: Vector3{x:-2.50645211865627, y: 1.9836004517922907, z:-0.09891371471172644},
+    z: Vector3{x: 0.06984542432319148, y:-0.192270896193362, z: 1.1248636144023192}
+};
+
+const ICTCP_TO_LMS_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.0, y: 1.0, z: 1.0},
+    y: Vector3{x: 0.008609037037932756, y:-0.008609037037932756, z: 0.5600313357106791},
+    z: Vector3{x: 0.11102962500302596, y:-0.11102962500302596, z:-0.32062717498731885}
+};
+
+/// *ITU-R BT.2020* constant luminance encoded values.
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct YcCbcCrc {
+    pub yc: f64,
+    pub cbc: f64,
+    pub crc: f64,
+}
+
+impl YcCbcCrc {
+    /// Encodes linear *RGB* values to *Y'cC'bcC'rc* values.
+    ///
+    /// Unlike the non-constant luminance *Y'CbCr* encoding, the luminance is computed from the
+    /// linear values before the transfer function is applied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::bt2020::YcCbcCrc;
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::LinearRgb;
+    ///
+    /// let red = LinearRgb{r: 1.0, g: 0.0, b: 0.0, space: ColorSpace::Bt2020};
+    /// let ycc = YcCbcCrc::from_linear_rgb(&red);
+    /// assert!((ycc.yc - 0.5030852).abs() < 1e-7);
+    /// assert!((ycc.cbc + 0.2592688).abs() < 1e-7);
+    /// assert!((ycc.crc - 0.5001155).abs() < 1e-7);
+    ///
+    /// let rgb = ycc.to_linear_rgb();
+    /// assert!((rgb.r - 1.0).abs() < 1e-9 && rgb.g.abs() < 1e-9 && rgb.b.abs() < 1e-9);
+    /// ```
+    pub fn from_linear_rgb(rgb: &LinearRgb) -> YcCbcCrc {
+        let rgb = rgb.to_linear_rgb(ColorSpace::Bt2020, None);
+        let space = ColorSpace::Bt2020;
+
+        let yc = space.encode(KR * rgb.r + (1.0 - KR - KB) * rgb.g + KB * rgb.b);
+        let (b, r) = (space.encode(rgb.b) - yc, space.encode(rgb.r) - yc);
+
+        YcCbcCrc{
+            yc,
+            cbc: b / if b <= 0.0 { NB } else { PB },
+            crc: r / if r <= 0.0 { NR } else { PR },
+        }
+    }
+
+    /// Decodes *Y'cC'bcC'rc* values to linear *RGB* values with the *BT.2020* primaries.
+    pub fn to_linear_rgb(&self) -> LinearRgb {
+        let space = ColorSpace::Bt2020;
+
+        let b = space.decode(self.yc + self.cbc * if self.cbc <= 0.0 { NB } else { PB });
+        let r = space.decode(self.yc + self.crc * if self.crc <= 0.0 { NR } else { PR });
+        let g = (space.decode(self.yc) - KR * r - KB * b) / (1.0 - KR - KB);
+
+        LinearRgb{r, g, b, space}
+    }
+}
+
+impl Color for YcCbcCrc {}
+
+/// Transfer functions the *ICtCp* encoding is defined for.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum HdrTransfer {
+    /// Perceptual quantizer, display referred, a linear value of 1.0 being 10000 cd/m².
+    Pq,
+
+    /// Hybrid log-gamma, scene referred, a linear value of 1.0 being the nominal peak.
+    Hlg,
+}
+
+impl HdrTransfer {
+    /// Returns the *BT.2100* colour space using the transfer function.
+    fn space(&self) -> ColorSpace {
+        match self {
+            HdrTransfer::Pq => ColorSpace::Bt2100Pq,
+            HdrTransfer::Hlg => ColorSpace::Bt2100Hlg,
+        }
+    }
+}
+
+/// *ITU-R BT.2100* *ICtCp* encoded values.
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct Ictcp {
+    pub i: f64,
+    pub ct: f64,
+    pub cp: f64,
+}
+
+impl Ictcp {
+    /// Encodes linear *RGB* values to *ICtCp* values.
+    ///
+    /// The linear values are scaled as in the *BT.2100* colour space of the transfer function,
+    /// i.e. as [`ColorSpace::Bt2100Pq`] or [`ColorSpace::Bt2100Hlg`] linear values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::bt2020::{HdrTransfer, Ictcp};
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::LinearRgb;
+    ///
+    /// // 100 cd/m² white.
+    /// let white = LinearRgb{r: 0.01, g: 0.01, b: 0.01, space: ColorSpace::Bt2100Pq};
+    /// let ictcp = Ictcp::from_linear_rgb(&white, HdrTransfer::Pq);
+    /// assert!((ictcp.i - 0.5080784).abs() < 1e-7 && ictcp.ct.abs() < 1e-9 && ictcp.cp.abs() < 1e-9);
+    ///
+    /// let rgb = ictcp.to_linear_rgb(HdrTransfer::Pq);
+    /// assert!((rgb.r - 0.01).abs() < 1e-9 && (rgb.g - 0.01).abs() < 1e-9 && (rgb.b - 0.01).abs() < 1e-9);
+    /// ```
+    pub fn from_linear_rgb(rgb: &LinearRgb, transfer: HdrTransfer) -> Ictcp {
+        let space = transfer.space();
+        let rgb = rgb.to_linear_rgb(space, None);
+        let lms = RGB_TO_LMS_MATRIX * Vector3{x: rgb.r, y: rgb.g, z: rgb.b};
+        let v = LMS_TO_ICTCP_MATRIX * lms.map(|c| space.encode(c));
+
+        Ictcp{i: v.x, ct: v.y, cp: v.z}
+    }
+
+    /// Decodes *ICtCp* values to linear *RGB* values, in the *BT.2100* colour space of the transfer
+    /// function.
+    pub fn to_linear_rgb(&self, transfer: HdrTransfer) -> LinearRgb {
+        let space = transfer.space();
+        let lms = ICTCP_TO_LMS_MATRIX * Vector3{x: self.i, y: self.ct, z: self.cp};
+        let v = LMS_TO_RGB_MATRIX * lms.map(|c| space.decode(c));
+
+        LinearRgb{r: v.x, g: v.y, b: v.z, space}
+    }
+}
+
+impl Color for Ictcp {}
+
+/// Returns the *ITU-R BT.2124* ΔE ITP colour difference between two *PQ* *ICtCp* colours.
+///
+/// A difference of 1 is a just noticeable difference.
+///
+/// # Example
+///
+/// ```
+/// use pigments::models::bt2020::{delta_e_itp, HdrTransfer, Ictcp};
+/// use pigments::models::rgb::color_space::ColorSpace;
+/// use pigments::models::rgb::LinearRgb;
+///
+/// let white = |l: f64| {
+///     let rgb = LinearRgb{r: l, g: l, b: l, space: ColorSpace::Bt2100Pq};
+///     Ictcp::from_linear_rgb(&rgb, HdrTransfer::Pq)
+/// };
+/// assert!((delta_e_itp(&white(0.01), &white(0.011)) - 6.9092127).abs() < 1e-6);
+/// ```
+pub fn delta_e_itp(a: &Ictcp, b: &Ictcp) -> f64 {
+    let di = a.i - b.i;
+    let dt = 0.5 * (a.ct - b.ct);
+    let dp = a.cp - b.cp;
+
+    720.0 * (di * di + dt * dt + dp * dp).sqrt()
+}
@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,16 +13,110 @@
 // limitations under the License.
 
 //! # CIE Lab color space
+//!
+//! *CIE 1976 L\*a\*b\** colourspace, using the exact *CIE* ε and κ constants rather than their
+//! rounded values.
+//!
+//! ## References
+//!
+//! [`CIE 15:2004` : Colorimetry, 3rd Edition.](https://www.cie.co.at/publications/colorimetry-3rd-edition)
+//! [`Lindbloom` : A Continuity Study of the CIE L* Function.](http://www.brucelindbloom.com/LContinuity.html)
 
 use super::Color;
+use crate::colorimetry::illuminants::{Illuminant, StandardObserver};
+use crate::models::cie_xyz::Xyz;
+use crate::models::illuminant_to_xyz;
 
-//#[derive(Eq, PartialEq, Clone, Copy, Hash, Default, Debug)]
+/// ε, the limit of the linear segment of the lightness function, (6/29)³.
+pub const EPSILON: f64 = 216.0 / 24389.0;
+
+/// κ, the slope of the linear segment of the lightness function, (29/3)³.
+pub const KAPPA: f64 = 24389.0 / 27.0;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
 pub struct CieLab {
-    l: f64,
-    a: f64,
-    b: f64,
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
 }
 
-impl CieLab {}
+impl CieLab {
+    /// Converts from *CIE XYZ* tristimulus values to *CIE L\*a\*b\** colourspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - *CIE XYZ* tristimulus values.
+    /// * `white` - reference illuminant, its whitepoint is the one of the
+    ///   *CIE 1931 2 Degree Standard Observer*.
+    ///
+    /// # Returns
+    ///
+    /// *CIE L\*a\*b\** colourspace values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_lab::CieLab;
+    /// use pigments::models::cie_xyz::Xyz;
+    ///
+    /// let xyz = Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952};
+    /// let lab = CieLab::from_xyz(&xyz, Illuminant::D65);
+    /// assert!((lab.l - 41.5278752).abs() < 1e-7);
+    /// assert!((lab.a - 52.6385830).abs() < 1e-7);
+    /// assert!((lab.b - 26.9231792).abs() < 1e-7);
+    /// ```
+    pub fn from_xyz(xyz: &Xyz, white: Illuminant) -> CieLab {
+        let w = illuminant_to_xyz(StandardObserver::Two, white);
+        let f = |t: f64| if t > EPSILON { t.cbrt() } else { (KAPPA * t + 16.0) / 116.0 };
+        let (fx, fy, fz) = (f(xyz.x / w.x), f(xyz.y / w.y), f(xyz.z / w.z));
+
+        CieLab{
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    /// Converts from *CIE L\*a\*b\** colourspace to *CIE XYZ* tristimulus values.
+    ///
+    /// # Arguments
+    ///
+    /// * `white` - reference illuminant, its whitepoint is the one of the
+    ///   *CIE 1931 2 Degree Standard Observer*.
+    ///
+    /// # Returns
+    ///
+    /// *CIE XYZ* tristimulus values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_lab::CieLab;
+    ///
+    /// let lab = CieLab{l: 41.5278752, a: 52.6385830, b: 26.9231792};
+    /// let xyz = lab.to_xyz(Illuminant::D65);
+    /// assert!((xyz.x - 0.20654008).abs() < 1e-7);
+    /// assert!((xyz.y - 0.12197225).abs() < 1e-7);
+    /// assert!((xyz.z - 0.05136952).abs() < 1e-7);
+    ///
+    /// let black = CieLab{l: 0.0, a: 0.0, b: 0.0};
+    /// assert_eq!(black.to_xyz(Illuminant::D65).y, 0.0);
+    /// ```
+    pub fn to_xyz(&self, white: Illuminant) -> Xyz {
+        let w = illuminant_to_xyz(StandardObserver::Two, white);
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+        let f_inv = |f: f64| {
+            let t = f * f * f;
+            if t > EPSILON { t } else { (116.0 * f - 16.0) / KAPPA }
+        };
+        let y = if self.l > KAPPA * EPSILON { fy * fy * fy } else { self.l / KAPPA };
+
+        Xyz{x: f_inv(fx) * w.x, y: y * w.y, z: f_inv(fz) * w.z}
+    }
+}
 
 impl Color for CieLab {}
@@ -0,0 +1,211 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # CIE LCh color spaces
+//!
+//! Cylindrical representations of *CIE L\*a\*b\** (*LCh(ab)*) and *CIE L\*u\*v\** (*LCh(uv)*):
+//! lightness, chroma and hue angle in degrees.
+//!
+//! ## Achromatic colours
+//!
+//! The hue of an achromatic colour is undefined. When the chroma is below
+//! [`ACHROMATIC_THRESHOLD`], the conversions set the hue to `NaN` rather than to an arbitrary
+//! angle, so that interpolations and palettes can tell a missing hue from a red one and don't
+//! introduce spurious hue jumps. A `NaN` hue is read as 0° when converting back to rectangular
+//! coordinates, and [`hue_difference`] treats it as no difference at all.
+
+use super::Color;
+use crate::models::cie_lab::CieLab;
+use crate::models::cie_luv::CieLuv;
+
+/// Chroma under which a colour is considered achromatic and its hue undefined, in *CIE L\*a\*b\**
+/// or *CIE L\*u\*v\** units. It is well below the just noticeable difference of about 1 while
+/// covering the residual chroma of greys in colour spaces with rounded matrices, e.g. 2.5e-3 for
+/// *Adobe RGB (1998)*.
+pub const ACHROMATIC_THRESHOLD: f64 = 1e-2;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct LchAb {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct LchUv {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl LchAb {
+    /// Converts from *CIE L\*a\*b\** colourspace to *CIE LCh(ab)* colourspace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_lab::CieLab;
+    /// use pigments::models::cie_lch::LchAb;
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let lch = LchAb::from_lab(&CieLab{l: 41.5278752, a: 52.6385830, b: 26.9231792});
+    /// assert!((lch.c - 59.1242590).abs() < 1e-7);
+    /// assert!((lch.h - 27.0884878).abs() < 1e-7);
+    ///
+    /// let grey = LchAb::from_lab(&CieLab{l: 50.0, a: 0.0, b: 0.0});
+    /// assert!(grey.h.is_nan());
+    ///
+    /// let white = ColorSpace::AdobeRgb1998.whitepoint();
+    /// let rgb = Rgb{r: 0.5, g: 0.5, b: 0.5, space: ColorSpace::AdobeRgb1998};
+    /// let grey = LchAb::from_lab(&CieLab::from_xyz(&rgb.to_xyz(None, white.clone()), white));
+    /// assert!(grey.c > 1e-3 && grey.h.is_nan());
+    /// ```
+    pub fn from_lab(lab: &CieLab) -> LchAb {
+        let (c, h) = to_polar(lab.a, lab.b, ACHROMATIC_THRESHOLD);
+
+        LchAb{l: lab.l, c, h}
+    }
+
+    /// Converts from *CIE LCh(ab)* colourspace to *CIE L\*a\*b\** colourspace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_lch::LchAb;
+    ///
+    /// let lab = LchAb{l: 41.5278752, c: 59.1242590, h: 27.0884878}.to_lab();
+    /// assert!((lab.a - 52.6385830).abs() < 1e-6 && (lab.b - 26.9231792).abs() < 1e-6);
+    ///
+    /// let grey = LchAb{l: 50.0, c: 0.0, h: f64::NAN}.to_lab();
+    /// assert_eq!((grey.a, grey.b), (0.0, 0.0));
+    /// ```
+    pub fn to_lab(&self) -> CieLab {
+        let (a, b) = to_rectangular(self.c, self.h);
+
+        CieLab{l: self.l, a, b}
+    }
+}
+
+impl LchUv {
+    /// Converts from *CIE L\*u\*v\** colourspace to *CIE LCh(uv)* colourspace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_luv::CieLuv;
+    /// use pigments::models::cie_lch::LchUv;
+    ///
+    /// let lch = LchUv::from_luv(&CieLuv{l: 41.5278752, u: 96.8362609, v: 17.7521014});
+    /// assert!((lch.c - 98.4499798).abs() < 1e-7);
+    /// assert!((lch.h - 10.3881634).abs() < 1e-7);
+    /// ```
+    pub fn from_luv(luv: &CieLuv) -> LchUv {
+        let (c, h) = to_polar(luv.u, luv.v, ACHROMATIC_THRESHOLD);
+
+        LchUv{l: luv.l, c, h}
+    }
+
+    /// Converts from *CIE LCh(uv)* colourspace to *CIE L\*u\*v\** colourspace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_lch::LchUv;
+    ///
+    /// let luv = LchUv{l: 41.5278752, c: 98.4499798, h: 370.3881634}.to_luv();
+    /// assert!((luv.u - 96.8362609).abs() < 1e-6 && (luv.v - 17.7521014).abs() < 1e-6);
+    /// ```
+    pub fn to_luv(&self) -> CieLuv {
+        let (u, v) = to_rectangular(self.c, self.h);
+
+        CieLuv{l: self.l, u, v}
+    }
+}
+
+impl Color for LchAb {}
+
+impl Color for LchUv {}
+
+/// Normalises a hue angle to the [0, 360[ domain, `NaN` is left untouched.
+///
+/// # Example
+///
+/// ```
+/// use pigments::models::cie_lch::normalize_hue;
+///
+/// assert_eq!(normalize_hue(-30.0), 330.0);
+/// assert_eq!(normalize_hue(720.0), 0.0);
+/// assert!(normalize_hue(f64::NAN).is_nan());
+/// ```
+pub fn normalize_hue(h: f64) -> f64 {
+    let h = h.rem_euclid(360.0);
+
+    // rem_euclid can round up to the modulus itself for tiny negative angles.
+    if h >= 360.0 { 0.0 } else { h }
+}
+
+/// Returns the signed difference `h2 - h1` between two hue angles along the shortest arc, in the
+/// ]-180, 180] domain.
+///
+/// An undefined (`NaN`) hue has no difference with any other hue.
+///
+/// # Example
+///
+/// ```
+/// use pigments::models::cie_lch::hue_difference;
+///
+/// assert_eq!(hue_difference(350.0, 10.0), 20.0);
+/// assert_eq!(hue_difference(10.0, 350.0), -20.0);
+/// assert_eq!(hue_difference(f64::NAN, 120.0), 0.0);
+/// ```
+pub fn hue_difference(h1: f64, h2: f64) -> f64 {
+    if h1.is_nan() || h2.is_nan() {
+        return 0.0;
+    }
+
+    let dh = normalize_hue(h2 - h1);
+    if dh > 180.0 { dh - 360.0 } else { dh }
+}
+
+/// Returns the metric hue difference ΔH between two colours, as used by the colour difference
+/// formulas, e.g. *CIE 1976* ΔH*ab: `2 √(C1 C2) sin(Δh / 2)`.
+///
+/// # Example
+///
+/// ```
+/// use pigments::models::cie_lch::metric_hue_difference;
+///
+/// assert!((metric_hue_difference(50.0, 0.0, 50.0, 60.0) - 50.0).abs() < 1e-9);
+/// assert_eq!(metric_hue_difference(0.0, f64::NAN, 50.0, 60.0), 0.0);
+/// ```
+pub fn metric_hue_difference(c1: f64, h1: f64, c2: f64, h2: f64) -> f64 {
+    2.0 * (c1 * c2).sqrt() * (hue_difference(h1, h2).to_radians() / 2.0).sin()
+}
+
+/// Converts rectangular chroma coordinates to chroma and hue angle in degrees, the hue being `NaN`
+/// below the `threshold` chroma.
+pub(crate) fn to_polar(x: f64, y: f64, threshold: f64) -> (f64, f64) {
+    let c = x.hypot(y);
+    let h = if c < threshold { f64::NAN } else { normalize_hue(y.atan2(x).to_degrees()) };
+
+    (c, h)
+}
+
+/// Converts chroma and hue angle in degrees to rectangular chroma coordinates.
+pub(crate) fn to_rectangular(c: f64, h: f64) -> (f64, f64) {
+    let h = if h.is_nan() { 0.0 } else { h.to_radians() };
+
+    (c * h.cos(), c * h.sin())
+}
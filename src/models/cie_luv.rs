@@ -13,3 +13,148 @@
 // limitations under the License.
 
 //! # CIE Luv color space
+//!
+//! *CIE 1976 L\*u\*v\** colourspace, built on the *CIE 1976 UCS* u'v' chromaticity coordinates.
+//!
+//! ## References
+//!
+//! [`CIE 15:2004` : Colorimetry, 3rd Edition.](https://www.cie.co.at/publications/colorimetry-3rd-edition)
+
+use super::Color;
+use crate::colorimetry::illuminants::{chromaticity, Illuminant, StandardObserver};
+use crate::models::cie_lab::{EPSILON, KAPPA};
+use crate::models::cie_xyz::Xyz;
+use crate::models::{illuminant_to_xyz, xy_to_uv_prime};
+
+use cgmath::Vector2;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct CieLuv {
+    pub l: f64,
+    pub u: f64,
+    pub v: f64,
+}
+
+impl CieLuv {
+    /// Converts from *CIE XYZ* tristimulus values to *CIE L\*u\*v\** colourspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - *CIE XYZ* tristimulus values.
+    /// * `white` - reference illuminant, its whitepoint is the one of the
+    ///   *CIE 1931 2 Degree Standard Observer*.
+    ///
+    /// # Returns
+    ///
+    /// *CIE L\*u\*v\** colourspace values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_luv::CieLuv;
+    /// use pigments::models::cie_xyz::Xyz;
+    ///
+    /// let xyz = Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952};
+    /// let luv = CieLuv::from_xyz(&xyz, Illuminant::D65);
+    /// assert!((luv.l - 41.5278752).abs() < 1e-7);
+    /// assert!((luv.u - 96.8362605).abs() < 1e-7);
+    /// assert!((luv.v - 17.7521015).abs() < 1e-7);
+    /// ```
+    pub fn from_xyz(xyz: &Xyz, white: Illuminant) -> CieLuv {
+        let w = illuminant_to_xyz(StandardObserver::Two, white.clone());
+        let uv_w = white_uv_prime(white);
+        let uv = uv_prime(xyz).unwrap_or(uv_w);
+        let y = xyz.y / w.y;
+        let l = if y > EPSILON { 116.0 * y.cbrt() - 16.0 } else { KAPPA * y };
+
+        CieLuv{
+            l,
+            u: 13.0 * l * (uv.x - uv_w.x),
+            v: 13.0 * l * (uv.y - uv_w.y),
+        }
+    }
+
+    /// Converts from *CIE L\*u\*v\** colourspace to *CIE XYZ* tristimulus values.
+    ///
+    /// # Arguments
+    ///
+    /// * `white` - reference illuminant, its whitepoint is the one of the
+    ///   *CIE 1931 2 Degree Standard Observer*.
+    ///
+    /// # Returns
+    ///
+    /// *CIE XYZ* tristimulus values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_luv::CieLuv;
+    ///
+    /// let luv = CieLuv{l: 41.5278752, u: 96.8362609, v: 17.7521014};
+    /// let xyz = luv.to_xyz(Illuminant::D65);
+    /// assert!((xyz.x - 0.20654008).abs() < 1e-7);
+    /// assert!((xyz.y - 0.12197225).abs() < 1e-7);
+    /// assert!((xyz.z - 0.05136952).abs() < 1e-7);
+    ///
+    /// let black = CieLuv{l: 0.0, u: 0.0, v: 0.0};
+    /// assert_eq!(black.to_xyz(Illuminant::D65), Default::default());
+    /// ```
+    pub fn to_xyz(&self, white: Illuminant) -> Xyz {
+        if self.l <= 0.0 {
+            return Xyz::default();
+        }
+
+        let w = illuminant_to_xyz(StandardObserver::Two, white.clone());
+        let uv_w = white_uv_prime(white);
+        let y = if self.l > KAPPA * EPSILON { ((self.l + 16.0) / 116.0).powi(3) } else { self.l / KAPPA } * w.y;
+        let u = self.u / (13.0 * self.l) + uv_w.x;
+        let v = self.v / (13.0 * self.l) + uv_w.y;
+
+        Xyz{
+            x: y * 9.0 * u / (4.0 * v),
+            y,
+            z: y * (12.0 - 3.0 * u - 20.0 * v) / (4.0 * v),
+        }
+    }
+
+    /// Returns the *CIE 1976 UCS* u'v' chromaticity coordinates of the colour.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_luv::CieLuv;
+    ///
+    /// let uv = CieLuv{l: 41.5278752, u: 96.8362609, v: 17.7521014}.to_uv_prime(Illuminant::D65);
+    /// assert!((uv.x - 0.3772021).abs() < 1e-7 && (uv.y - 0.5012026).abs() < 1e-7);
+    /// ```
+    pub fn to_uv_prime(&self, white: Illuminant) -> Vector2<f64> {
+        let uv_w = white_uv_prime(white);
+        if self.l <= 0.0 {
+            return uv_w;
+        }
+
+        Vector2{x: self.u / (13.0 * self.l) + uv_w.x, y: self.v / (13.0 * self.l) + uv_w.y}
+    }
+}
+
+impl Color for CieLuv {}
+
+/// Returns the *CIE 1976 UCS* u'v' chromaticity coordinates of *CIE XYZ* tristimulus values, `None`
+/// for black.
+fn uv_prime(xyz: &Xyz) -> Option<Vector2<f64>> {
+    let d = xyz.x + 15.0 * xyz.y + 3.0 * xyz.z;
+    if d == 0.0 {
+        return None;
+    }
+
+    Some(Vector2{x: 4.0 * xyz.x / d, y: 9.0 * xyz.y / d})
+}
+
+/// Returns the *CIE 1976 UCS* u'v' chromaticity coordinates of an illuminant for the
+/// *CIE 1931 2 Degree Standard Observer*.
+pub(crate) fn white_uv_prime(white: Illuminant) -> Vector2<f64> {
+    xy_to_uv_prime(&chromaticity::illuminant(StandardObserver::Two, white))
+}
@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,3 +13,92 @@
 // limitations under the License.
 
 //! # CIE UCS color space
+//!
+//! *CIE 1960 UCS* colourspace, its *UVW* tristimulus values and its uv chromaticity coordinates.
+//! The chromaticity coordinates of the *CIE 1976 UCS* derive from it: u' = u and v' = 1.5 v.
+//!
+//! ## References
+//!
+//! [`Wikipedia` : CIE 1960 color space.](https://en.wikipedia.org/wiki/CIE_1960_color_space)
+
+use super::Color;
+use crate::models::cie_xyz::Xyz;
+use crate::models::uv_to_uv_prime;
+
+use cgmath::Vector2;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct CieUcs {
+    pub u: f64,
+    pub v: f64,
+    pub w: f64,
+}
+
+impl CieUcs {
+    /// Converts from *CIE XYZ* tristimulus values to *CIE 1960 UCS* colourspace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_ucs::CieUcs;
+    /// use pigments::models::cie_xyz::Xyz;
+    ///
+    /// let ucs = CieUcs::from_xyz(&Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952});
+    /// assert!((ucs.u - 0.13769339).abs() < 1e-8);
+    /// assert!((ucs.v - 0.12197225).abs() < 1e-8);
+    /// assert!((ucs.w - 0.10537310).abs() < 1e-8);
+    /// ```
+    pub fn from_xyz(xyz: &Xyz) -> CieUcs {
+        CieUcs{
+            u: 2.0 / 3.0 * xyz.x,
+            v: xyz.y,
+            w: 0.5 * (-xyz.x + 3.0 * xyz.y + xyz.z),
+        }
+    }
+
+    /// Converts from *CIE 1960 UCS* colourspace to *CIE XYZ* tristimulus values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_ucs::CieUcs;
+    ///
+    /// let xyz = CieUcs{u: 0.13769339, v: 0.12197225, w: 0.10537310}.to_xyz();
+    /// assert!((xyz.x - 0.20654008).abs() < 1e-7);
+    /// assert!((xyz.y - 0.12197225).abs() < 1e-7);
+    /// assert!((xyz.z - 0.05136952).abs() < 1e-7);
+    /// ```
+    pub fn to_xyz(&self) -> Xyz {
+        Xyz{
+            x: 1.5 * self.u,
+            y: self.v,
+            z: 1.5 * self.u - 3.0 * self.v + 2.0 * self.w,
+        }
+    }
+
+    /// Returns the *CIE 1960 UCS* uv chromaticity coordinates, `None` for black.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_ucs::CieUcs;
+    ///
+    /// let uv = CieUcs{u: 0.13769339, v: 0.12197225, w: 0.10537310}.to_uv().unwrap();
+    /// assert!((uv.x - 0.37720213).abs() < 1e-7 && (uv.y - 0.33413508).abs() < 1e-7);
+    /// ```
+    pub fn to_uv(&self) -> Option<Vector2<f64>> {
+        let sum = self.u + self.v + self.w;
+        if sum == 0.0 {
+            return None;
+        }
+
+        Some(Vector2{x: self.u / sum, y: self.v / sum})
+    }
+
+    /// Returns the *CIE 1976 UCS* u'v' chromaticity coordinates, `None` for black.
+    pub fn to_uv_prime(&self) -> Option<Vector2<f64>> {
+        self.to_uv().map(|uv| uv_to_uv_prime(&uv))
+    }
+}
+
+impl Color for CieUcs {}
@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,3 +13,103 @@
 // limitations under the License.
 
 //! # CIE UVW color space
+//!
+//! *CIE 1964 U\*V\*W\** colourspace, used by the *CIE 1995* colour rendering index.
+//!
+//! ## References
+//!
+//! [`Wikipedia` : CIE 1964 color space.](https://en.wikipedia.org/wiki/CIE_1964_color_space)
+
+use super::Color;
+use crate::colorimetry::illuminants::{chromaticity, Illuminant, StandardObserver};
+use crate::models::cie_xyz::Xyz;
+use crate::models::{uv_to_xy, xy_to_uv, xy_to_xyy};
+
+use cgmath::Vector2;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct CieUvw {
+    pub u: f64,
+    pub v: f64,
+    pub w: f64,
+}
+
+impl CieUvw {
+    /// Converts from *CIE XYZ* tristimulus values to *CIE 1964 U\*V\*W\** colourspace.
+    ///
+    /// The lightness W* is defined for a luminance in [0, 100], the tristimulus values are scaled
+    /// from the [0, 1] domain used by the crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - *CIE XYZ* tristimulus values.
+    /// * `white` - reference illuminant, its chromaticity coordinates are the ones of the
+    ///   *CIE 1931 2 Degree Standard Observer*.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_uvw::CieUvw;
+    /// use pigments::models::cie_xyz::Xyz;
+    ///
+    /// let xyz = Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952};
+    /// let uvw = CieUvw::from_xyz(&xyz, Illuminant::D65);
+    /// assert!((uvw.u - 94.5503572).abs() < 1e-6);
+    /// assert!((uvw.v - 11.5553652).abs() < 1e-6);
+    /// assert!((uvw.w - 40.5475740).abs() < 1e-6);
+    /// ```
+    pub fn from_xyz(xyz: &Xyz, white: Illuminant) -> CieUvw {
+        let uv_w = white_uv(white);
+        let sum = xyz.x + xyz.y + xyz.z;
+        let uv = if sum == 0.0 { uv_w } else { xy_to_uv(&Vector2{x: xyz.x / sum, y: xyz.y / sum}) };
+        let w = 25.0 * (100.0 * xyz.y).cbrt() - 17.0;
+
+        CieUvw{
+            u: 13.0 * w * (uv.x - uv_w.x),
+            v: 13.0 * w * (uv.y - uv_w.y),
+            w,
+        }
+    }
+
+    /// Converts from *CIE 1964 U\*V\*W\** colourspace to *CIE XYZ* tristimulus values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_uvw::CieUvw;
+    ///
+    /// let uvw = CieUvw{u: 94.5503572, v: 11.5553652, w: 40.5475740};
+    /// let xyz = uvw.to_xyz(Illuminant::D65);
+    /// assert!((xyz.x - 0.20654008).abs() < 1e-7);
+    /// assert!((xyz.y - 0.12197225).abs() < 1e-7);
+    /// assert!((xyz.z - 0.05136952).abs() < 1e-7);
+    ///
+    /// // W* = 0 is a dark grey, with the chromaticity of the whitepoint.
+    /// let grey = CieUvw{u: 0.0, v: 0.0, w: 0.0}.to_xyz(Illuminant::D65);
+    /// assert!((grey.x - 0.0029885).abs() < 1e-7 && (grey.y - 0.0031443).abs() < 1e-7);
+    /// assert!((grey.z - 0.0034244).abs() < 1e-7);
+    /// let uvw = CieUvw::from_xyz(&grey, Illuminant::D65);
+    /// assert!(uvw.u.abs() < 1e-9 && uvw.v.abs() < 1e-9 && uvw.w.abs() < 1e-9);
+    /// ```
+    pub fn to_xyz(&self, white: Illuminant) -> Xyz {
+        let uv_w = white_uv(white);
+        let y = ((self.w + 17.0) / 25.0).powi(3) / 100.0;
+        let uv = if self.w == 0.0 {
+            uv_w
+        } else {
+            Vector2{x: self.u / (13.0 * self.w) + uv_w.x, y: self.v / (13.0 * self.w) + uv_w.y}
+        };
+
+        xy_to_xyy(&uv_to_xy(&uv), y).to_xyz()
+    }
+}
+
+impl Color for CieUvw {}
+
+/// Returns the *CIE 1960 UCS* uv chromaticity coordinates of an illuminant for the
+/// *CIE 1931 2 Degree Standard Observer*.
+fn white_uv(white: Illuminant) -> Vector2<f64> {
+    xy_to_uv(&chromaticity::illuminant(StandardObserver::Two, white))
+}
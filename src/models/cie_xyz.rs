@@ -14,8 +14,14 @@
 
 //! # CIE 1931 XYZ color space
 
+use crate::adaptation::vonkries::chromatic_adaptation_matrix;
+use crate::adaptation::{AdaptationTransform, DEFAULT_TRANSFORM};
 use crate::models::cie_xyy;
+use crate::models::illuminant_to_xyz;
+use crate::models::rgb::color_space::ColorSpace;
+use crate::models::rgb::{LinearRgb, Rgb};
 use crate::colorimetry::illuminants::{StandardObserver, Illuminant};
+use cgmath::Vector3;
 
 #[derive(PartialEq, Clone, Copy, Default, Debug)]
 pub struct Xyz {
@@ -62,6 +68,98 @@ impl Xyz {
         }
     }
 
+    /// Adapts *CIE XYZ* tristimulus values from a `source` illuminant to a `target` illuminant with
+    /// the *Von Kries* method, using the *CIE 1931 2 Degree Standard Observer* whitepoints.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::adaptation::AdaptationTransform;
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_xyz::Xyz;
+    ///
+    /// let xyz = Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952};
+    /// let d50 = xyz.adapt(Illuminant::D65, Illuminant::D50, AdaptationTransform::Bradford);
+    /// assert!((d50.x - 0.2166600).abs() < 1e-7);
+    /// assert!((d50.y - 0.1260478).abs() < 1e-7);
+    /// assert!((d50.z - 0.0385507).abs() < 1e-7);
+    /// ```
+    pub fn adapt(&self, source: Illuminant, target: Illuminant, transform: AdaptationTransform) -> Xyz {
+        if source == target {
+            return *self;
+        }
+
+        let xyz_w = illuminant_to_xyz(StandardObserver::Two, source);
+        let xyz_wr = illuminant_to_xyz(StandardObserver::Two, target);
+        let v = chromatic_adaptation_matrix(&xyz_w, &xyz_wr, &transform) * Vector3{x: self.x, y: self.y, z: self.z};
+
+        Xyz{x: v.x, y: v.y, z: v.z}
+    }
+
+    /// Converts from *CIE XYZ* tristimulus values to linear *RGB* values.
+    ///
+    /// # Arguments
+    ///
+    /// * `white` - illuminant the tristimulus values are relative to.
+    /// * `space` - target *RGB* colour space.
+    /// * `adaptation` - chromatic adaptation transform applied from `white` to the colour space
+    ///   whitepoint when they differ, [`DEFAULT_TRANSFORM`] when `None`.
+    ///
+    /// # Returns
+    ///
+    /// Linear *RGB* values.
+    pub fn to_linear_rgb(
+        &self,
+        white: Illuminant,
+        space: ColorSpace,
+        adaptation: Option<AdaptationTransform>,
+    ) -> LinearRgb {
+        let xyz = self.adapt(white, space.whitepoint(), adaptation.unwrap_or(DEFAULT_TRANSFORM));
+        let v = space.xyz_to_rgb_matrix() * Vector3{x: xyz.x, y: xyz.y, z: xyz.z};
+
+        LinearRgb{r: v.x, g: v.y, b: v.z, space}
+    }
+
+    /// Converts from *CIE XYZ* tristimulus values to transfer function encoded *RGB* values.
+    ///
+    /// Unlike the *RGB* to *RGB* conversions, it takes the illuminant the tristimulus values are
+    /// relative to, since *CIE XYZ* values do not carry it.
+    ///
+    /// # Arguments
+    ///
+    /// * `white` - illuminant the tristimulus values are relative to.
+    /// * `space` - target *RGB* colour space.
+    /// * `adaptation` - chromatic adaptation transform applied from `white` to the colour space
+    ///   whitepoint when they differ, [`DEFAULT_TRANSFORM`] when `None`.
+    ///
+    /// # Returns
+    ///
+    /// *RGB* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::adaptation::AdaptationTransform;
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let xyz = Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952};
+    /// let rgb = xyz.to_rgb(Illuminant::D65, ColorSpace::Srgb, None);
+    /// assert!((rgb.r - 0.7057765).abs() < 1e-7);
+    /// assert!((rgb.g - 0.1923229).abs() < 1e-7);
+    /// assert!((rgb.b - 0.2235045).abs() < 1e-7);
+    ///
+    /// let rgb = xyz.to_rgb(Illuminant::D50, ColorSpace::Srgb, Some(AdaptationTransform::Bradford));
+    /// assert!((rgb.r - 0.6834954).abs() < 1e-7);
+    /// assert!((rgb.g - 0.2005849).abs() < 1e-7);
+    /// assert!((rgb.b - 0.2697104).abs() < 1e-7);
+    /// assert_eq!(xyz.to_rgb(Illuminant::D50, ColorSpace::Srgb, None), rgb);
+    /// ```
+    pub fn to_rgb(&self, white: Illuminant, space: ColorSpace, adaptation: Option<AdaptationTransform>) -> Rgb {
+        self.to_linear_rgb(white, space, adaptation).encode()
+    }
+
 //    'XYZ_to_ATD95',
 //    'XYZ_to_CAM16',
 //    'XYZ_to_CIECAM02',
@@ -77,7 +175,6 @@ impl Xyz {
 //    'XYZ_to_Lab',
 //    'XYZ_to_Luv',
 //    'XYZ_to_OSA_UCS',
-//    'XYZ_to_RGB',
 //    'XYZ_to_UCS',
 //    'XYZ_to_UVW',
 //    'XYZ_to_hdr_CIELab',
@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,3 +13,53 @@
 // limitations under the License.
 
 //! # CMY (cyan, magenta, yellow) color model
+//!
+//! Subtractive complement of transfer function encoded *RGB* values, tied to their *RGB* colour
+//! space. It is a device-independent approximation, not a substitute for a printer profile.
+
+use super::Color;
+use crate::models::rgb::color_space::{ColorSpace, RgbSpace};
+use crate::models::rgb::Rgb;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Cmy<S = ColorSpace> {
+    pub c: f64,
+    pub m: f64,
+    pub y: f64,
+
+    pub space: S,
+}
+
+impl<S: Copy> Cmy<S> {
+    /// Converts from *RGB* values to *CMY* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cmy::Cmy;
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let rgb = Rgb{r: 0.25, g: 0.5, b: 0.75, space: ColorSpace::Srgb};
+    /// let cmy = Cmy::from_rgb(&rgb);
+    /// assert_eq!((cmy.c, cmy.m, cmy.y), (0.75, 0.5, 0.25));
+    /// assert_eq!(cmy.to_rgb(), rgb);
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<S>) -> Cmy<S> {
+        Cmy{c: 1.0 - rgb.r, m: 1.0 - rgb.g, y: 1.0 - rgb.b, space: rgb.space}
+    }
+
+    /// Converts from *CMY* values to *RGB* values.
+    pub fn to_rgb(&self) -> Rgb<S> {
+        Rgb{r: 1.0 - self.c, g: 1.0 - self.m, b: 1.0 - self.y, space: self.space}
+    }
+}
+
+impl<S: RgbSpace> Cmy<S> {
+    /// Creates *CMY* values in a compile-time colour space.
+    pub fn new(c: f64, m: f64, y: f64) -> Self {
+        Cmy{c, m, y, space: S::default()}
+    }
+}
+
+impl<S> Color for Cmy<S> {}
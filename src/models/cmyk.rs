@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,3 +13,183 @@
 // limitations under the License.
 
 //! # CMYK (cyan, magenta, yellow, black) color model
+//!
+//! Four inks separation of *CMY* values, tied to their *RGB* colour space. The amount of black
+//! and how much of the chromatic inks it replaces are controlled by a [`Separation`]; this is a
+//! device-independent approximation to use until a printer profile is supplied.
+//!
+//! The grey component of a colour is the smallest of its *CMY* values: the part of the three
+//! chromatic inks that only darkens the colour and that black ink can print instead.
+
+use super::Color;
+use crate::models::cmy::Cmy;
+use crate::models::rgb::color_space::{ColorSpace, RgbSpace};
+use crate::models::rgb::Rgb;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Cmyk<S = ColorSpace> {
+    pub c: f64,
+    pub m: f64,
+    pub y: f64,
+    pub k: f64,
+
+    pub space: S,
+}
+
+/// Strategy deciding which colours receive black ink.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum BlackGeneration {
+    /// Under colour removal: black only replaces the chromatic inks of near neutral colours, in
+    /// proportion of their neutrality (grey component over the largest *CMY* value).
+    UnderColorRemoval,
+
+    /// Grey component replacement: black replaces the grey component of every colour, neutral or
+    /// saturated.
+    GreyComponentReplacement,
+}
+
+/// Separation settings of *CMY* values into *CMYK* values.
+///
+/// The black generated for a grey component `g` is
+/// `max_black * ((g - black_start) / (1 - black_start)) ^ black_curve`, zero under the start point,
+/// and never more than the grey component itself. The default settings replace the whole grey
+/// component with black and don't limit the total ink, which is the naive *CMYK* conversion.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Separation {
+    /// Colours receiving black ink.
+    pub black_generation: BlackGeneration,
+
+    /// Grey component, in [0, 1], from which black ink starts being generated; no black is generated
+    /// at 1.
+    pub black_start: f64,
+
+    /// Exponent of the black generation curve, values over 1 keep the black in the shadows.
+    pub black_curve: f64,
+
+    /// Amount of black generated for a full grey component, in [0, 1].
+    pub max_black: f64,
+
+    /// Total area coverage limit, the maximum sum of the four inks, in [0, 4], e.g. 3.0 for 300%.
+    pub total_area_coverage: f64,
+}
+
+impl Default for Separation {
+    fn default() -> Self {
+        Separation{
+            black_generation: BlackGeneration::GreyComponentReplacement,
+            black_start: 0.0,
+            black_curve: 1.0,
+            max_black: 1.0,
+            total_area_coverage: 4.0,
+        }
+    }
+}
+
+impl Separation {
+    /// Returns the black ink generated for *CMY* values.
+    fn black(&self, c: f64, m: f64, y: f64) -> f64 {
+        let grey = c.min(m).min(y).max(0.0);
+        let t = if grey > self.black_start {
+            ((grey - self.black_start) / (1.0 - self.black_start)).min(1.0)
+        } else {
+            0.0
+        };
+        let k = (self.max_black * t.powf(self.black_curve)).min(grey);
+
+        match self.black_generation {
+            BlackGeneration::GreyComponentReplacement => k,
+            BlackGeneration::UnderColorRemoval => {
+                let max = c.max(m).max(y);
+                if max > 0.0 { k * grey / max } else { 0.0 }
+            }
+        }
+    }
+}
+
+impl<S: Copy> Cmyk<S> {
+    /// Separates *CMY* values into *CMYK* values.
+    ///
+    /// The chromatic inks are reduced by the generated black, then scaled down together when the
+    /// total area coverage limit is exceeded; black is only reduced when it exceeds the limit by
+    /// itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cmy::Cmy;
+    /// use pigments::models::cmyk::{Cmyk, Separation};
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let cmy = Cmy{c: 0.9, m: 0.9, y: 0.8, space: ColorSpace::Srgb};
+    /// let separation = Separation{black_start: 0.5, total_area_coverage: 2.4, ..Default::default()};
+    /// let cmyk = Cmyk::from_cmy(&cmy, &separation);
+    /// assert!((cmyk.k - 0.6).abs() < 1e-9);
+    /// assert!((cmyk.c - 0.675).abs() < 1e-9 && (cmyk.y - 0.45).abs() < 1e-9);
+    /// assert!((cmyk.c + cmyk.m + cmyk.y + cmyk.k - 2.4).abs() < 1e-9);
+    ///
+    /// let separation = Separation{black_start: 1.0, ..Default::default()};
+    /// for v in [0.0, 0.5, 1.0].iter() {
+    ///     let cmyk = Cmyk::from_cmy(&Cmy{c: *v, m: *v, y: *v, space: ColorSpace::Srgb}, &separation);
+    ///     assert_eq!((cmyk.c, cmyk.m, cmyk.y, cmyk.k), (*v, *v, *v, 0.0));
+    /// }
+    /// ```
+    pub fn from_cmy(cmy: &Cmy<S>, separation: &Separation) -> Cmyk<S> {
+        let k = separation.black(cmy.c, cmy.m, cmy.y);
+        let remove = |v: f64| if k < 1.0 { ((v - k) / (1.0 - k)).max(0.0) } else { 0.0 };
+        let (c, m, y) = (remove(cmy.c), remove(cmy.m), remove(cmy.y));
+
+        let tac = separation.total_area_coverage;
+        let k = k.min(tac);
+        let inks = c + m + y;
+        let scale = if inks + k > tac { (tac - k) / inks } else { 1.0 };
+
+        Cmyk{c: c * scale, m: m * scale, y: y * scale, k, space: cmy.space}
+    }
+
+    /// Converts from *CMYK* values back to *CMY* values.
+    ///
+    /// The inverse is exact unless the total area coverage limit was applied.
+    pub fn to_cmy(&self) -> Cmy<S> {
+        let add = |v: f64| v * (1.0 - self.k) + self.k;
+
+        Cmy{c: add(self.c), m: add(self.m), y: add(self.y), space: self.space}
+    }
+
+    /// Converts from *RGB* values to *CMYK* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cmyk::{BlackGeneration, Cmyk, Separation};
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let rgb = Rgb{r: 0.2, g: 0.4, b: 0.6, space: ColorSpace::Srgb};
+    /// let cmyk = Cmyk::from_rgb(&rgb, &Separation::default());
+    /// assert!((cmyk.c - 2.0 / 3.0).abs() < 1e-9 && (cmyk.m - 1.0 / 3.0).abs() < 1e-9);
+    /// assert!(cmyk.y.abs() < 1e-9 && (cmyk.k - 0.4).abs() < 1e-9);
+    ///
+    /// let rgb_back = cmyk.to_rgb();
+    /// assert!((rgb_back.r - 0.2).abs() < 1e-9 && (rgb_back.g - 0.4).abs() < 1e-9 && (rgb_back.b - 0.6).abs() < 1e-9);
+    ///
+    /// let ucr = Separation{black_generation: BlackGeneration::UnderColorRemoval, ..Default::default()};
+    /// assert!(Cmyk::from_rgb(&rgb, &ucr).k < cmyk.k);
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<S>, separation: &Separation) -> Cmyk<S> {
+        Cmyk::from_cmy(&Cmy::from_rgb(rgb), separation)
+    }
+
+    /// Converts from *CMYK* values to *RGB* values.
+    pub fn to_rgb(&self) -> Rgb<S> {
+        self.to_cmy().to_rgb()
+    }
+}
+
+impl<S: RgbSpace> Cmyk<S> {
+    /// Creates *CMYK* values in a compile-time colour space.
+    pub fn new(c: f64, m: f64, y: f64, k: f64) -> Self {
+        Cmyk{c, m, y, k, space: S::default()}
+    }
+}
+
+impl<S> Color for Cmyk<S> {}
@@ -0,0 +1,214 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # DIN99 color spaces
+//!
+//! *DIN99* colourspace and its *DIN99b*, *DIN99c* and *DIN99d* refinements, logarithmic
+//! compressions of [`CieLab`](crate::models::cie_lab::CieLab) in which the euclidean distance is
+//! a colour difference formula comparable to *CIE DE2000*.
+//!
+//! *DIN99c* and *DIN99d* also modify the *CIE XYZ* `X` tristimulus value before the conversion to
+//! *CIE L\*a\*b\**, which only the conversions from and to *CIE XYZ* apply.
+//!
+//! ## References
+//!
+//! [`ASTM D2244-07` : Standard Practice for Calculation of Color Tolerances and Color Differences from Instrumentally Measured Color Coordinates.](https://www.astm.org/d2244-07.html)
+//! [`Cui et al. (2002)` : Uniform colour spaces based on the DIN99 colour-difference formula.](https://doi.org/10.1002/col.10066)
+
+use super::Color;
+use crate::colorimetry::illuminants::{Illuminant, StandardObserver};
+use crate::models::cie_lab::CieLab;
+use crate::models::cie_xyz::Xyz;
+use crate::models::illuminant_to_xyz;
+
+/// *DIN99* variant.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Variant {
+    /// *DIN 6176* / *ASTM D2244-07* formula.
+    Din99,
+    /// *Cui et al. (2002)* formula.
+    Din99b,
+    /// *Cui et al. (2002)* formula with a modified `X` tristimulus value.
+    Din99c,
+    /// *Cui et al. (2002)* formula with a modified `X` tristimulus value, also fitted to the
+    /// blue region.
+    Din99d,
+}
+
+impl Variant {
+    /// Returns the lightness scale and offset, the hue rotation in degrees, the `f` axis scale, the
+    /// chroma scale and offset, and the hue offset in degrees.
+    fn coefficients(self) -> [f64; 7] {
+        match self {
+            Variant::Din99 => [105.509, 0.0158, 16.0, 0.7, 200.0 / 9.0, 9.0 / 200.0, 0.0],
+            Variant::Din99b => [303.67, 0.0039, 26.0, 0.83, 23.0, 0.075, 26.0],
+            Variant::Din99c => [317.65, 0.0037, 0.0, 0.94, 23.0, 0.066, 0.0],
+            Variant::Din99d => [325.22, 0.0036, 50.0, 1.14, 22.5, 0.06, 50.0],
+        }
+    }
+
+    /// Returns the `(x, z)` weights of the modified `X' = x X - z Z` tristimulus value.
+    fn x_modification(self) -> (f64, f64) {
+        match self {
+            Variant::Din99 | Variant::Din99b => (1.0, 0.0),
+            Variant::Din99c => (1.1, 0.1),
+            Variant::Din99d => (1.12, 0.12),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct Din99 {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl Din99 {
+    /// Converts from *CIE L\*a\*b\** colourspace to *DIN99* colourspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `lab` - *CIE L\*a\*b\** colourspace values.
+    /// * `variant` - *DIN99* variant.
+    ///
+    /// # Returns
+    ///
+    /// *DIN99* colourspace values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_lab::CieLab;
+    /// use pigments::models::din99::{Din99, Variant};
+    ///
+    /// let lab = CieLab{l: 41.52787529, a: 52.63858304, b: 26.92317922};
+    /// let din99 = Din99::from_lab(&lab, Variant::Din99);
+    /// assert!((din99.l - 53.2282199).abs() < 1e-7);
+    /// assert!((din99.a - 28.4163466).abs() < 1e-7);
+    /// assert!((din99.b - 3.8983955).abs() < 1e-7);
+    ///
+    /// let din99d = Din99::from_lab(&lab, Variant::Din99d);
+    /// assert!((din99d.l - 45.3120475).abs() < 1e-7);
+    /// assert!((din99d.a - 31.4210672).abs() < 1e-7);
+    /// assert!((din99d.b - 14.1700465).abs() < 1e-7);
+    /// ```
+    pub fn from_lab(lab: &CieLab, variant: Variant) -> Din99 {
+        let [c1, c2, c3, c4, c5, c6, c7] = variant.coefficients();
+        let (sin, cos) = c3.to_radians().sin_cos();
+
+        let e = cos * lab.a + sin * lab.b;
+        let f = c4 * (cos * lab.b - sin * lab.a);
+        let g = e.hypot(f);
+
+        let c = c5 * (c6 * g).ln_1p();
+        let h = f.atan2(e) + c7.to_radians();
+
+        Din99{
+            l: c1 * (c2 * lab.l).ln_1p(),
+            a: c * h.cos(),
+            b: c * h.sin(),
+        }
+    }
+
+    /// Converts from *DIN99* colourspace to *CIE L\*a\*b\** colourspace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::din99::{Din99, Variant};
+    ///
+    /// let lab = Din99{l: 45.5830314, a: 34.7182449, b: 17.6162215}.to_lab(Variant::Din99b);
+    /// assert!((lab.l - 41.5278753).abs() < 1e-6);
+    /// assert!((lab.a - 52.6385830).abs() < 1e-6);
+    /// assert!((lab.b - 26.9231792).abs() < 1e-6);
+    /// ```
+    pub fn to_lab(&self, variant: Variant) -> CieLab {
+        let [c1, c2, c3, c4, c5, c6, c7] = variant.coefficients();
+        let (sin, cos) = c3.to_radians().sin_cos();
+
+        let g = (self.a.hypot(self.b) / c5).exp_m1() / c6;
+        let h = self.b.atan2(self.a) - c7.to_radians();
+        let (e, f) = (g * h.cos(), g * h.sin() / c4);
+
+        CieLab{
+            l: (self.l / c1).exp_m1() / c2,
+            a: e * cos - f * sin,
+            b: e * sin + f * cos,
+        }
+    }
+
+    /// Converts from *CIE XYZ* tristimulus values to *DIN99* colourspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - *CIE XYZ* tristimulus values.
+    /// * `white` - reference illuminant, its whitepoint is the one of the
+    ///   *CIE 1931 2 Degree Standard Observer*.
+    /// * `variant` - *DIN99* variant.
+    ///
+    /// # Returns
+    ///
+    /// *DIN99* colourspace values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::din99::{Din99, Variant};
+    ///
+    /// let xyz = Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952};
+    /// let din99c = Din99::from_xyz(&xyz, Illuminant::D65, Variant::Din99c);
+    /// assert!((din99c.l - 45.4028421).abs() < 1e-7);
+    /// assert!((din99c.a - 35.8255366).abs() < 1e-7);
+    /// assert!((din99c.b - 14.7432031).abs() < 1e-7);
+    ///
+    /// let xyz_back = din99c.to_xyz(Illuminant::D65, Variant::Din99c);
+    /// assert!((xyz_back.x - xyz.x).abs() < 1e-12 && (xyz_back.y - xyz.y).abs() < 1e-12);
+    /// assert!((xyz_back.z - xyz.z).abs() < 1e-12);
+    /// ```
+    pub fn from_xyz(xyz: &Xyz, white: Illuminant, variant: Variant) -> Din99 {
+        let (xc, zc) = variant.x_modification();
+        let w = illuminant_to_xyz(StandardObserver::Two, white.clone());
+
+        // The whitepoint is modified like the tristimulus values, rescaling X keeps the X / Xn ratio
+        // consistent with the unmodified whitepoint used by `CieLab`.
+        let x = (xc * xyz.x - zc * xyz.z) * w.x / (xc * w.x - zc * w.z);
+
+        Din99::from_lab(&CieLab::from_xyz(&Xyz{x, y: xyz.y, z: xyz.z}, white), variant)
+    }
+
+    /// Converts from *DIN99* colourspace to *CIE XYZ* tristimulus values.
+    ///
+    /// # Arguments
+    ///
+    /// * `white` - reference illuminant, its whitepoint is the one of the
+    ///   *CIE 1931 2 Degree Standard Observer*.
+    /// * `variant` - *DIN99* variant.
+    ///
+    /// # Returns
+    ///
+    /// *CIE XYZ* tristimulus values.
+    pub fn to_xyz(&self, white: Illuminant, variant: Variant) -> Xyz {
+        let (xc, zc) = variant.x_modification();
+        let w = illuminant_to_xyz(StandardObserver::Two, white.clone());
+        let xyz = self.to_lab(variant).to_xyz(white);
+        let x = xyz.x * (xc * w.x - zc * w.z) / w.x;
+
+        Xyz{x: (x + zc * xyz.z) / xc, y: xyz.y, z: xyz.z}
+    }
+}
+
+impl Color for Din99 {}
@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,3 +13,133 @@
 // limitations under the License.
 
 //! # Hex Triplet color definition
+//!
+//! Parsing and formatting of hexadecimal colour notations, `#rgb`, `#rgba`, `#rrggbb` and
+//! `#rrggbbaa`, as used by *CSS* and most colour pickers. The digits are transfer function encoded
+//! values of an *RGB* colour space, *sRGB* unless specified otherwise.
+//!
+//! ## References
+//!
+//! [`CSS Color Module Level 4` : 5.2. The RGB hexadecimal notations.](https://www.w3.org/TR/css-color-4/#hex-notation)
+
+use crate::models::rgb::color_space::ColorSpace;
+use crate::models::rgb::Rgb;
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Error returned when parsing a malformed hexadecimal colour.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum HexError {
+    /// The number of digits is not 3, 4, 6 or 8.
+    InvalidLength(usize),
+
+    /// A character is not an hexadecimal digit.
+    InvalidDigit(char),
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HexError::InvalidLength(n) => {
+                write!(f, "invalid hex colour length {}, expected 3, 4, 6 or 8 digits", n)
+            }
+            HexError::InvalidDigit(c) => write!(f, "invalid hex colour digit {:?}", c),
+        }
+    }
+}
+
+impl Error for HexError {}
+
+/// Parses a hexadecimal colour into *RGB* values in the given colour space.
+///
+/// The leading `#` is optional and the digits are case-insensitive.
+///
+/// # Returns
+///
+/// The *RGB* values and the alpha, 1 when the notation has no alpha digits.
+///
+/// # Example
+///
+/// ```
+/// use pigments::models::hex::{parse, HexError};
+/// use pigments::models::rgb::color_space::ColorSpace;
+///
+/// let (rgb, alpha) = parse("#FF8000", ColorSpace::DisplayP3).unwrap();
+/// assert_eq!((rgb.r, rgb.g, rgb.b, rgb.space), (1.0, 128.0 / 255.0, 0.0, ColorSpace::DisplayP3));
+/// assert_eq!(alpha, 1.0);
+///
+/// let (rgb, alpha) = parse("f808", ColorSpace::Srgb).unwrap();
+/// assert_eq!((rgb.r, rgb.g, rgb.b, alpha), (1.0, 136.0 / 255.0, 0.0, 136.0 / 255.0));
+///
+/// assert_eq!(parse("#ff", ColorSpace::Srgb), Err(HexError::InvalidLength(2)));
+/// assert_eq!(parse("#ff800g", ColorSpace::Srgb), Err(HexError::InvalidDigit('g')));
+/// assert_eq!(parse("#ff80000", ColorSpace::Srgb), Err(HexError::InvalidLength(7)));
+/// ```
+pub fn parse(hex: &str, space: ColorSpace) -> Result<(Rgb, f64), HexError> {
+    let digits = hex.strip_prefix('#').unwrap_or(hex);
+    let values = digits
+        .chars()
+        .map(|c| c.to_digit(16).ok_or(HexError::InvalidDigit(c)))
+        .collect::<Result<Vec<u32>, HexError>>()?;
+
+    let channels: Vec<f64> = match values.len() {
+        3 | 4 => values.iter().map(|v| (v * 17) as f64 / 255.0).collect(),
+        6 | 8 => values.chunks(2).map(|v| (v[0] * 16 + v[1]) as f64 / 255.0).collect(),
+        n => return Err(HexError::InvalidLength(n)),
+    };
+    let alpha = channels.get(3).copied().unwrap_or(1.0);
+
+    Ok((Rgb{r: channels[0], g: channels[1], b: channels[2], space}, alpha))
+}
+
+/// Formats *RGB* values as a lowercase `#rrggbb` hexadecimal colour, or `#rrggbbaa` when an alpha
+/// is given.
+///
+/// The values are clamped to [0, 1] and rounded to the nearest 8-bit code value, halves being
+/// rounded up.
+///
+/// # Example
+///
+/// ```
+/// use pigments::models::hex::{format, parse};
+/// use pigments::models::rgb::color_space::ColorSpace;
+/// use pigments::models::rgb::Rgb;
+///
+/// let rgb = Rgb{r: 1.2, g: 0.5, b: -0.1, space: ColorSpace::Srgb};
+/// assert_eq!(format(&rgb, None), "#ff8000");
+/// assert_eq!(format(&rgb, Some(0.25)), "#ff800040");
+///
+/// let (rgb, alpha) = parse("#12ABef80", ColorSpace::Srgb).unwrap();
+/// assert_eq!(format(&rgb, Some(alpha)), "#12abef80");
+/// ```
+pub fn format<S>(rgb: &Rgb<S>, alpha: Option<f64>) -> String {
+    let byte = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let mut hex = format!("#{:02x}{:02x}{:02x}", byte(rgb.r), byte(rgb.g), byte(rgb.b));
+    if let Some(alpha) = alpha {
+        hex.push_str(&format!("{:02x}", byte(alpha)));
+    }
+
+    hex
+}
+
+/// Parses a hexadecimal colour into *sRGB* values, any alpha digits are ignored.
+///
+/// # Example
+///
+/// ```
+/// use pigments::models::rgb::color_space::ColorSpace;
+/// use pigments::models::rgb::Rgb;
+///
+/// let rgb: Rgb = "#0f0".parse().unwrap();
+/// assert_eq!(rgb, Rgb{r: 0.0, g: 1.0, b: 0.0, space: ColorSpace::Srgb});
+/// assert!("#00ff00ff0".parse::<Rgb>().is_err());
+/// ```
+impl FromStr for Rgb {
+    type Err = HexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s, ColorSpace::Srgb).map(|(rgb, _)| rgb)
+    }
+}
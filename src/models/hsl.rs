@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,3 +13,121 @@
 // limitations under the License.
 
 //! # HSL (hue, saturation, lightness) color space
+//!
+//! Cylindrical representation of transfer function encoded *RGB* values. It is only meaningful
+//! relative to the *RGB* colour space it was computed from, which it carries along like [`Rgb`].
+//!
+//! The hue is in degrees, the saturation and lightness in the [0, 1] domain. The conversions follow
+//! *CSS Color 4*: the hue of achromatic colours is `NaN` (read as 0° when converting back), and
+//! the negative saturation of out of gamut colours is turned positive by rotating the hue by 180°.
+//!
+//! ## References
+//!
+//! [`CSS Color Module Level 4` : 7. HSL Colors.](https://www.w3.org/TR/css-color-4/#the-hsl-notation)
+
+use super::Color;
+use crate::models::cie_lch::normalize_hue;
+use crate::models::rgb::color_space::{ColorSpace, RgbSpace};
+use crate::models::rgb::Rgb;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Hsl<S = ColorSpace> {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+
+    pub space: S,
+}
+
+impl<S: Copy> Hsl<S> {
+    /// Converts from *RGB* values to *HSL* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::hsl::Hsl;
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let hsl = Hsl::from_rgb(&Rgb{r: 0.2, g: 0.4, b: 0.6, space: ColorSpace::Srgb});
+    /// assert!((hsl.h - 210.0).abs() < 1e-9 && (hsl.s - 0.5).abs() < 1e-9 && (hsl.l - 0.4).abs() < 1e-9);
+    ///
+    /// let grey = Hsl::from_rgb(&Rgb{r: 0.5, g: 0.5, b: 0.5, space: ColorSpace::Srgb});
+    /// assert!(grey.h.is_nan() && grey.s == 0.0);
+    ///
+    /// // Out of gamut values with a negative saturation have their hue rotated instead.
+    /// let hsl = Hsl::from_rgb(&Rgb{r: 1.2, g: 1.1, b: 1.05, space: ColorSpace::Srgb});
+    /// assert!((hsl.h - 200.0).abs() < 1e-9 && hsl.s > 0.0);
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<S>) -> Hsl<S> {
+        let max = rgb.r.max(rgb.g).max(rgb.b);
+        let min = rgb.r.min(rgb.g).min(rgb.b);
+        let l = (min + max) / 2.0;
+        let d = max - min;
+
+        let (h, s) = if d == 0.0 {
+            (f64::NAN, 0.0)
+        } else {
+            let s = if l == 0.0 || l == 1.0 { 0.0 } else { (max - l) / l.min(1.0 - l) };
+
+            (hue(rgb.r, rgb.g, rgb.b), s)
+        };
+        let (h, s) = if s < 0.0 { (normalize_hue(h + 180.0), -s) } else { (h, s) };
+
+        Hsl{h, s, l, space: rgb.space}
+    }
+
+    /// Converts from *HSL* values to *RGB* values.
+    ///
+    /// A negative saturation is clamped to 0, as *CSS* does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::hsl::Hsl;
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let rgb = Hsl{h: -150.0, s: 0.5, l: 0.4, space: ColorSpace::Srgb}.to_rgb();
+    /// assert!((rgb.r - 0.2).abs() < 1e-9 && (rgb.g - 0.4).abs() < 1e-9 && (rgb.b - 0.6).abs() < 1e-9);
+    ///
+    /// let grey = Hsl{h: f64::NAN, s: -0.5, l: 0.5, space: ColorSpace::Srgb}.to_rgb();
+    /// assert_eq!((grey.r, grey.g, grey.b), (0.5, 0.5, 0.5));
+    /// ```
+    pub fn to_rgb(&self) -> Rgb<S> {
+        let h = if self.h.is_nan() { 0.0 } else { normalize_hue(self.h) };
+        let a = self.s.max(0.0) * self.l.min(1.0 - self.l);
+        let f = |n: f64| {
+            let k = (n + h / 30.0) % 12.0;
+
+            self.l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+
+        Rgb{r: f(0.0), g: f(8.0), b: f(4.0), space: self.space}
+    }
+}
+
+impl<S: RgbSpace> Hsl<S> {
+    /// Creates *HSL* values in a compile-time colour space.
+    pub fn new(h: f64, s: f64, l: f64) -> Self {
+        Hsl{h, s, l, space: S::default()}
+    }
+}
+
+impl<S> Color for Hsl<S> {}
+
+/// Returns the hue angle in degrees shared by the *HSL*, *HSV* and *HWB* models, the values must not
+/// be achromatic.
+pub(crate) fn hue(r: f64, g: f64, b: f64) -> f64 {
+    let max = r.max(g).max(b);
+    let d = max - r.min(g).min(b);
+
+    let h = if max == r {
+        (g - b) / d + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+
+    normalize_hue(h * 60.0)
+}
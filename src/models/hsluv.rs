@@ -0,0 +1,239 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # HSLuv and HPLuv color spaces
+//!
+//! Hue, saturation and lightness models built on [`LchUv`](crate::models::cie_lch::LchUv),
+//! relative to *CIE Illuminant D65*, for perceptually even colour pickers.
+//!
+//! *HSLuv* stretches the chroma so that the saturation is 100 on the boundary of the *sRGB* gamut,
+//! for every lightness and hue. *HPLuv* ("pastel") scales it with the largest chroma inside the
+//! gamut for every hue, which keeps the hues uniform but restricts the saturation domain to pastel
+//! colours.
+//!
+//! Hues follow the [`LchUv`](crate::models::cie_lch::LchUv) conventions: degrees, `NaN` for
+//! achromatic colours and read as 0 when converting back.
+//!
+//! ## References
+//!
+//! [`Boronine (2015)` : HSLuv - Human-friendly HSL.](https://www.hsluv.org/math/)
+
+use super::Color;
+use crate::colorimetry::illuminants::Illuminant;
+use crate::models::cie_lab::{EPSILON, KAPPA};
+use crate::models::cie_lch::LchUv;
+use crate::models::cie_luv::{white_uv_prime, CieLuv};
+use crate::models::rgb::color_space::{ColorSpace, Srgb};
+use crate::models::rgb::Rgb;
+
+use cgmath::*;
+
+/// Lightness above which a colour is white.
+const MAX_LIGHTNESS: f64 = 100.0 - 1e-7;
+
+/// Lightness below which a colour is black.
+const MIN_LIGHTNESS: f64 = 1e-8;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct Hsluv {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct Hpluv {
+    pub h: f64,
+    pub p: f64,
+    pub l: f64,
+}
+
+impl Hsluv {
+    /// Converts from *CIE LCh(uv)* colourspace, relative to *CIE Illuminant D65*, to *HSLuv*.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_lch::LchUv;
+    /// use pigments::models::hsluv::Hsluv;
+    ///
+    /// let hsluv = Hsluv::from_lch(&LchUv{l: 50.0, c: 50.0, h: 120.0});
+    /// assert!((hsluv.h - 120.0).abs() < 1e-9 && (hsluv.s - 72.5948351).abs() < 1e-7 && hsluv.l == 50.0);
+    /// ```
+    pub fn from_lch(lch: &LchUv) -> Hsluv {
+        let s = if lch.l > MAX_LIGHTNESS || lch.l < MIN_LIGHTNESS {
+            0.0
+        } else {
+            lch.c / max_chroma(lch.l, lch.h) * 100.0
+        };
+
+        Hsluv{h: lch.h, s, l: lch.l.clamp(0.0, 100.0)}
+    }
+
+    /// Converts from *HSLuv* to *CIE LCh(uv)* colourspace, relative to *CIE Illuminant D65*.
+    pub fn to_lch(&self) -> LchUv {
+        let c = if self.l > MAX_LIGHTNESS || self.l < MIN_LIGHTNESS {
+            0.0
+        } else {
+            max_chroma(self.l, self.h) * self.s / 100.0
+        };
+
+        LchUv{l: self.l.clamp(0.0, 100.0), c, h: self.h}
+    }
+
+    /// Converts from *sRGB* values to *HSLuv* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::hsluv::Hsluv;
+    /// use pigments::models::rgb::color_space::Srgb;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let hsluv = Hsluv::from_rgb(&Rgb::<Srgb>::new(1.0, 0.0, 0.0));
+    /// assert!((hsluv.h - 12.1770506).abs() < 1e-6 && (hsluv.s - 100.0).abs() < 1e-6);
+    /// assert!((hsluv.l - 53.2371156).abs() < 1e-6);
+    ///
+    /// let rgb = Hsluv{h: 12.1770506, s: 100.0, l: 53.2371156}.to_rgb();
+    /// assert!((rgb.r - 1.0).abs() < 1e-6 && rgb.g.abs() < 1e-6 && rgb.b.abs() < 1e-6);
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<Srgb>) -> Hsluv {
+        Hsluv::from_lch(&to_lch(rgb))
+    }
+
+    /// Converts from *HSLuv* values to *sRGB* values.
+    pub fn to_rgb(&self) -> Rgb<Srgb> {
+        to_rgb(&self.to_lch())
+    }
+}
+
+impl Hpluv {
+    /// Converts from *CIE LCh(uv)* colourspace, relative to *CIE Illuminant D65*, to *HPLuv*.
+    ///
+    /// The saturation exceeds 100 for colours outside of the chroma range that is in gamut for all
+    /// hues.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_lch::LchUv;
+    /// use pigments::models::hsluv::Hpluv;
+    ///
+    /// let hpluv = Hpluv::from_lch(&LchUv{l: 50.0, c: 50.0, h: 120.0});
+    /// assert!((hpluv.h - 120.0).abs() < 1e-9 && (hpluv.p - 126.8934854).abs() < 1e-7 && hpluv.l == 50.0);
+    /// ```
+    pub fn from_lch(lch: &LchUv) -> Hpluv {
+        let p = if lch.l > MAX_LIGHTNESS || lch.l < MIN_LIGHTNESS {
+            0.0
+        } else {
+            lch.c / max_safe_chroma(lch.l) * 100.0
+        };
+
+        Hpluv{h: lch.h, p, l: lch.l.clamp(0.0, 100.0)}
+    }
+
+    /// Converts from *HPLuv* to *CIE LCh(uv)* colourspace, relative to *CIE Illuminant D65*.
+    pub fn to_lch(&self) -> LchUv {
+        let c = if self.l > MAX_LIGHTNESS || self.l < MIN_LIGHTNESS {
+            0.0
+        } else {
+            max_safe_chroma(self.l) * self.p / 100.0
+        };
+
+        LchUv{l: self.l.clamp(0.0, 100.0), c, h: self.h}
+    }
+
+    /// Converts from *sRGB* values to *HPLuv* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::hsluv::Hpluv;
+    /// use pigments::models::rgb::color_space::Srgb;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let rgb = Rgb::<Srgb>::new(0.2, 0.4, 0.6);
+    /// let hpluv = Hpluv::from_rgb(&rgb);
+    /// let rgb_back = hpluv.to_rgb();
+    /// assert!((hpluv.h - 246.9424403).abs() < 1e-7 && (hpluv.p - 156.1314559).abs() < 1e-7);
+    /// assert!((rgb_back.r - 0.2).abs() < 1e-9 && (rgb_back.g - 0.4).abs() < 1e-9);
+    /// assert!((rgb_back.b - 0.6).abs() < 1e-9);
+    ///
+    /// let grey = Hpluv::from_rgb(&Rgb::<Srgb>::new(0.5, 0.5, 0.5));
+    /// assert!(grey.h.is_nan() && grey.p.abs() < 1e-6);
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<Srgb>) -> Hpluv {
+        Hpluv::from_lch(&to_lch(rgb))
+    }
+
+    /// Converts from *HPLuv* values to *sRGB* values.
+    pub fn to_rgb(&self) -> Rgb<Srgb> {
+        to_rgb(&self.to_lch())
+    }
+}
+
+impl Color for Hsluv {}
+
+impl Color for Hpluv {}
+
+fn to_lch(rgb: &Rgb<Srgb>) -> LchUv {
+    LchUv::from_luv(&CieLuv::from_xyz(&rgb.to_xyz(None, Illuminant::D65), Illuminant::D65))
+}
+
+fn to_rgb(lch: &LchUv) -> Rgb<Srgb> {
+    let rgb = lch.to_luv().to_xyz(Illuminant::D65).to_rgb(Illuminant::D65, ColorSpace::Srgb, None);
+
+    Rgb::new(rgb.r, rgb.g, rgb.b)
+}
+
+/// Returns the lines `a u + b v + d = 0` bounding the *sRGB* gamut in the *CIE L\*u\*v\** plane of
+/// a lightness, one for each of the 0 and 1 limits of each component.
+fn bounds(l: f64) -> Vec<(f64, f64, f64)> {
+    let y = if l > KAPPA * EPSILON { ((l + 16.0) / 116.0).powi(3) } else { l / KAPPA };
+    let white = white_uv_prime(Illuminant::D65);
+    let m = ColorSpace::Srgb.xyz_to_rgb_matrix();
+
+    // Substituting X and Z from u', v' and Y in a row of the matrix gives a line in the u'v' plane,
+    // and u' = u / 13L + u'n, v' = v / 13L + v'n are affine.
+    (0..3)
+        .flat_map(|i| {
+            let row = m.row(i);
+
+            [0.0, 1.0].iter().map(move |t| {
+                let a = (9.0 * row.x - 3.0 * row.z) * y;
+                let b = (4.0 * row.y - 20.0 * row.z) * y - 4.0 * t;
+                let d = a * white.x + b * white.y + 12.0 * row.z * y;
+
+                (a / (13.0 * l), b / (13.0 * l), d)
+            })
+        })
+        .collect()
+}
+
+/// Returns the largest chroma inside the *sRGB* gamut for a lightness and a hue in degrees.
+fn max_chroma(l: f64, h: f64) -> f64 {
+    let h = if h.is_nan() { 0.0 } else { h.to_radians() };
+    let (sin, cos) = h.sin_cos();
+
+    bounds(l)
+        .iter()
+        .map(|(a, b, d)| -d / (a * cos + b * sin))
+        .filter(|c| *c >= 0.0)
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Returns the largest chroma inside the *sRGB* gamut for a lightness and every hue.
+fn max_safe_chroma(l: f64) -> f64 {
+    bounds(l).iter().map(|(a, b, d)| d.abs() / a.hypot(*b)).fold(f64::INFINITY, f64::min)
+}
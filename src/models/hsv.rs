@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,3 +13,87 @@
 // limitations under the License.
 
 //! # HSV (hue, saturation, value) color space
+//!
+//! Cylindrical representation of transfer function encoded *RGB* values, tied to their *RGB*
+//! colour space like [`Hsl`](crate::models::hsl::Hsl), and sharing its hue and edge-case
+//! conventions.
+
+use super::Color;
+use crate::models::cie_lch::normalize_hue;
+use crate::models::hsl::hue;
+use crate::models::rgb::color_space::{ColorSpace, RgbSpace};
+use crate::models::rgb::Rgb;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Hsv<S = ColorSpace> {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+
+    pub space: S,
+}
+
+impl<S: Copy> Hsv<S> {
+    /// Converts from *RGB* values to *HSV* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::hsv::Hsv;
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let hsv = Hsv::from_rgb(&Rgb{r: 0.2, g: 0.4, b: 0.6, space: ColorSpace::Srgb});
+    /// assert!((hsv.h - 210.0).abs() < 1e-9 && (hsv.s - 2.0 / 3.0).abs() < 1e-9 && hsv.v == 0.6);
+    ///
+    /// let black = Hsv::from_rgb(&Rgb{r: 0.0, g: 0.0, b: 0.0, space: ColorSpace::Srgb});
+    /// assert!(black.h.is_nan() && black.s == 0.0);
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<S>) -> Hsv<S> {
+        let max = rgb.r.max(rgb.g).max(rgb.b);
+        let d = max - rgb.r.min(rgb.g).min(rgb.b);
+
+        let (h, s) = if d == 0.0 {
+            (f64::NAN, 0.0)
+        } else {
+            (hue(rgb.r, rgb.g, rgb.b), if max == 0.0 { 0.0 } else { d / max })
+        };
+        let (h, s) = if s < 0.0 { (normalize_hue(h + 180.0), -s) } else { (h, s) };
+
+        Hsv{h, s, v: max, space: rgb.space}
+    }
+
+    /// Converts from *HSV* values to *RGB* values.
+    ///
+    /// A negative saturation is clamped to 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::hsv::Hsv;
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let rgb = Hsv{h: 570.0, s: 2.0 / 3.0, v: 0.6, space: ColorSpace::Srgb}.to_rgb();
+    /// assert!((rgb.r - 0.2).abs() < 1e-9 && (rgb.g - 0.4).abs() < 1e-9 && (rgb.b - 0.6).abs() < 1e-9);
+    /// ```
+    pub fn to_rgb(&self) -> Rgb<S> {
+        let h = if self.h.is_nan() { 0.0 } else { normalize_hue(self.h) };
+        let s = self.s.max(0.0);
+        let f = |n: f64| {
+            let k = (n + h / 60.0) % 6.0;
+
+            self.v - self.v * s * k.min(4.0 - k).clamp(0.0, 1.0)
+        };
+
+        Rgb{r: f(5.0), g: f(3.0), b: f(1.0), space: self.space}
+    }
+}
+
+impl<S: RgbSpace> Hsv<S> {
+    /// Creates *HSV* values in a compile-time colour space.
+    pub fn new(h: f64, s: f64, v: f64) -> Self {
+        Hsv{h, s, v, space: S::default()}
+    }
+}
+
+impl<S> Color for Hsv<S> {}
@@ -0,0 +1,146 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Hunter Lab color space
+//!
+//! *Hunter L,a,b* colourspace, the opponent colour scale still reported by many reflectance
+//! instruments. The chromaticness coefficients `Ka` and `Kb` are derived from the whitepoint
+//! tristimulus values, which yields `Ka = 175` and `Kb = 70` for *CIE Illuminant C*, the
+//! illuminant the scale was originally defined for.
+//!
+//! ## References
+//!
+//! [`HunterLab (2008)` : Hunter L,a,b Color Scale.](http://www.hunterlab.se/wp-content/uploads/2012/11/Hunter-L-a-b.pdf)
+//! [`HunterLab (2008)` : Illuminant Factors in Universal Software and EasyMatch Coatings.](https://support.hunterlab.com/hc/en-us/article_attachments/201437785/an02_02.pdf)
+
+use super::Color;
+use crate::colorimetry::illuminants::{Illuminant, StandardObserver};
+use crate::models::cie_xyz::Xyz;
+use crate::models::illuminant_to_xyz;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct HunterLab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+/// Returns the *Hunter L,a,b* chromaticness coefficients `(Ka, Kb)` of an illuminant.
+///
+/// # Arguments
+///
+/// * `o` - standard observer.
+/// * `i` - illuminant.
+///
+/// # Example
+///
+/// ```
+/// use pigments::colorimetry::illuminants::{Illuminant, StandardObserver};
+/// use pigments::models::hunter_lab::k_ab;
+///
+/// let (ka, kb) = k_ab(StandardObserver::Two, Illuminant::C);
+/// assert!((ka - 175.0270375).abs() < 1e-7 && (kb - 70.0368917).abs() < 1e-7);
+///
+/// let (ka, kb) = k_ab(StandardObserver::Ten, Illuminant::D65);
+/// assert!((ka - 172.1454850).abs() < 1e-7 && (kb - 66.5322980).abs() < 1e-7);
+/// ```
+pub fn k_ab(o: StandardObserver, i: Illuminant) -> (f64, f64) {
+    let w = illuminant_to_xyz(o, i);
+
+    (175.0 / 198.04 * (w.x + w.y) * 100.0, 70.0 / 218.11 * (w.y + w.z) * 100.0)
+}
+
+impl HunterLab {
+    /// Converts from *CIE XYZ* tristimulus values to *Hunter L,a,b* colourspace.
+    ///
+    /// The chromaticness of black, which is undefined, is 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - *CIE XYZ* tristimulus values.
+    /// * `o` - standard observer of the whitepoint.
+    /// * `i` - reference illuminant.
+    ///
+    /// # Returns
+    ///
+    /// *Hunter L,a,b* colourspace values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::{Illuminant, StandardObserver};
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::hunter_lab::HunterLab;
+    ///
+    /// let xyz = Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952};
+    /// let lab = HunterLab::from_xyz(&xyz, StandardObserver::Two, Illuminant::D65);
+    /// assert!((lab.l - 34.9245258).abs() < 1e-7);
+    /// assert!((lab.a - 47.0477534).abs() < 1e-7);
+    /// assert!((lab.b - 14.3603215).abs() < 1e-7);
+    /// ```
+    pub fn from_xyz(xyz: &Xyz, o: StandardObserver, i: Illuminant) -> HunterLab {
+        let w = illuminant_to_xyz(o.clone(), i.clone());
+        let (ka, kb) = k_ab(o, i);
+        let (x, y, z) = (xyz.x / w.x, xyz.y / w.y, xyz.z / w.z);
+        let l = y.sqrt();
+
+        if l == 0.0 {
+            return HunterLab{l: 0.0, a: 0.0, b: 0.0};
+        }
+
+        HunterLab{
+            l: 100.0 * l,
+            a: ka * (x - y) / l,
+            b: kb * (y - z) / l,
+        }
+    }
+
+    /// Converts from *Hunter L,a,b* colourspace to *CIE XYZ* tristimulus values.
+    ///
+    /// # Arguments
+    ///
+    /// * `o` - standard observer of the whitepoint.
+    /// * `i` - reference illuminant.
+    ///
+    /// # Returns
+    ///
+    /// *CIE XYZ* tristimulus values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::{Illuminant, StandardObserver};
+    /// use pigments::models::hunter_lab::HunterLab;
+    ///
+    /// let lab = HunterLab{l: 34.9245258, a: 47.0477534, b: 14.3603215};
+    /// let xyz = lab.to_xyz(StandardObserver::Two, Illuminant::D65);
+    /// assert!((xyz.x - 0.20654008).abs() < 1e-8);
+    /// assert!((xyz.y - 0.12197225).abs() < 1e-8);
+    /// assert!((xyz.z - 0.05136952).abs() < 1e-8);
+    /// ```
+    pub fn to_xyz(&self, o: StandardObserver, i: Illuminant) -> Xyz {
+        let w = illuminant_to_xyz(o.clone(), i.clone());
+        let (ka, kb) = k_ab(o, i);
+        let l = self.l / 100.0;
+        let y = l * l;
+
+        Xyz{
+            x: (self.a / ka * l + y) * w.x,
+            y: y * w.y,
+            z: (y - self.b / kb * l) * w.z,
+        }
+    }
+}
+
+impl Color for HunterLab {}
@@ -0,0 +1,120 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Hunter Rd,a,b color space
+//!
+//! *Hunter Rd,a,b* colourspace, a variant of [`HunterLab`](crate::models::hunter_lab::HunterLab)
+//! using the luminous reflectance `Rd` as lightness, with the chromaticness scaled by a function
+//! of `Rd` to keep it comparable to the *Hunter L,a,b* one.
+//!
+//! ## References
+//!
+//! [`HunterLab (2012)` : Hunter Rd,a,b Color Scale – History and Application.](https://hunterlabdotcom.files.wordpress.com/2012/07/an-1016-hunter-rd-a-b-color-scale-update-12-07-03.pdf)
+
+use super::Color;
+use crate::colorimetry::illuminants::{Illuminant, StandardObserver};
+use crate::models::cie_xyz::Xyz;
+use crate::models::hunter_lab::k_ab;
+use crate::models::illuminant_to_xyz;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct HunterRdab {
+    pub rd: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl HunterRdab {
+    /// Converts from *CIE XYZ* tristimulus values to *Hunter Rd,a,b* colourspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - *CIE XYZ* tristimulus values.
+    /// * `o` - standard observer of the whitepoint.
+    /// * `i` - reference illuminant.
+    ///
+    /// # Returns
+    ///
+    /// *Hunter Rd,a,b* colourspace values, `Rd` being a percentage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::{Illuminant, StandardObserver};
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::hunter_rdab::HunterRdab;
+    ///
+    /// let xyz = Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952};
+    /// let rdab = HunterRdab::from_xyz(&xyz, StandardObserver::Two, Illuminant::D65);
+    /// assert!((rdab.rd - 12.197225).abs() < 1e-7);
+    /// assert!((rdab.a - 57.1082088).abs() < 1e-7);
+    /// assert!((rdab.b - 17.4310606).abs() < 1e-7);
+    /// ```
+    pub fn from_xyz(xyz: &Xyz, o: StandardObserver, i: Illuminant) -> HunterRdab {
+        let w = illuminant_to_xyz(o.clone(), i.clone());
+        let (ka, kb) = k_ab(o, i);
+        let (x, y, z) = (xyz.x / w.x, xyz.y / w.y, xyz.z / w.z);
+        let rd = 100.0 * y;
+        let f = chromaticness_factor(rd);
+
+        HunterRdab{
+            rd,
+            a: ka * f * (x - y),
+            b: kb * f * (y - z),
+        }
+    }
+
+    /// Converts from *Hunter Rd,a,b* colourspace to *CIE XYZ* tristimulus values.
+    ///
+    /// # Arguments
+    ///
+    /// * `o` - standard observer of the whitepoint.
+    /// * `i` - reference illuminant.
+    ///
+    /// # Returns
+    ///
+    /// *CIE XYZ* tristimulus values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::{Illuminant, StandardObserver};
+    /// use pigments::models::hunter_rdab::HunterRdab;
+    ///
+    /// let rdab = HunterRdab{rd: 12.197225, a: 57.1082088, b: 17.4310606};
+    /// let xyz = rdab.to_xyz(StandardObserver::Two, Illuminant::D65);
+    /// assert!((xyz.x - 0.20654008).abs() < 1e-8);
+    /// assert!((xyz.y - 0.12197225).abs() < 1e-8);
+    /// assert!((xyz.z - 0.05136952).abs() < 1e-8);
+    /// ```
+    pub fn to_xyz(&self, o: StandardObserver, i: Illuminant) -> Xyz {
+        let w = illuminant_to_xyz(o.clone(), i.clone());
+        let (ka, kb) = k_ab(o, i);
+        let f = chromaticness_factor(self.rd);
+        let y = self.rd / 100.0;
+
+        Xyz{
+            x: (self.a / (ka * f) + y) * w.x,
+            y: y * w.y,
+            z: (y - self.b / (kb * f)) * w.z,
+        }
+    }
+}
+
+impl Color for HunterRdab {}
+
+/// Scaling of the chromaticness as a function of the luminous reflectance.
+fn chromaticness_factor(rd: f64) -> f64 {
+    0.51 * (21.0 + 0.2 * rd) / (1.0 + 0.2 * rd)
+}
@@ -0,0 +1,97 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # HWB (hue, whiteness, blackness) color space
+//!
+//! Cylindrical representation of transfer function encoded *RGB* values, tied to their *RGB*
+//! colour space like [`Hsl`](crate::models::hsl::Hsl), and sharing its hue conventions.
+//!
+//! ## References
+//!
+//! [`CSS Color Module Level 4` : 8. HWB Colors.](https://www.w3.org/TR/css-color-4/#the-hwb-notation)
+
+use super::Color;
+use crate::models::hsl::{hue, Hsl};
+use crate::models::rgb::color_space::{ColorSpace, RgbSpace};
+use crate::models::rgb::Rgb;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Hwb<S = ColorSpace> {
+    pub h: f64,
+    pub w: f64,
+    pub b: f64,
+
+    pub space: S,
+}
+
+impl<S: Copy> Hwb<S> {
+    /// Converts from *RGB* values to *HWB* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::hwb::Hwb;
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let hwb = Hwb::from_rgb(&Rgb{r: 0.2, g: 0.4, b: 0.6, space: ColorSpace::Srgb});
+    /// assert!((hwb.h - 210.0).abs() < 1e-9 && hwb.w == 0.2 && (hwb.b - 0.4).abs() < 1e-9);
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<S>) -> Hwb<S> {
+        let max = rgb.r.max(rgb.g).max(rgb.b);
+        let min = rgb.r.min(rgb.g).min(rgb.b);
+        let h = if max == min { f64::NAN } else { hue(rgb.r, rgb.g, rgb.b) };
+
+        Hwb{h, w: min, b: 1.0 - max, space: rgb.space}
+    }
+
+    /// Converts from *HWB* values to *RGB* values.
+    ///
+    /// When the whiteness and blackness add up to 1 or more, they are normalised and the result is
+    /// the corresponding grey, as *CSS* does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::hwb::Hwb;
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let rgb = Hwb{h: 210.0, w: 0.2, b: 0.4, space: ColorSpace::Srgb}.to_rgb();
+    /// assert!((rgb.r - 0.2).abs() < 1e-9 && (rgb.g - 0.4).abs() < 1e-9 && (rgb.b - 0.6).abs() < 1e-9);
+    ///
+    /// let grey = Hwb{h: 120.0, w: 0.6, b: 0.6, space: ColorSpace::Srgb}.to_rgb();
+    /// assert_eq!((grey.r, grey.g, grey.b), (0.5, 0.5, 0.5));
+    /// ```
+    pub fn to_rgb(&self) -> Rgb<S> {
+        if self.w + self.b >= 1.0 {
+            let grey = self.w / (self.w + self.b);
+
+            return Rgb{r: grey, g: grey, b: grey, space: self.space};
+        }
+
+        let pure = Hsl{h: self.h, s: 1.0, l: 0.5, space: self.space}.to_rgb();
+        let f = |c: f64| c * (1.0 - self.w - self.b) + self.w;
+
+        Rgb{r: f(pure.r), g: f(pure.g), b: f(pure.b), space: self.space}
+    }
+}
+
+impl<S: RgbSpace> Hwb<S> {
+    /// Creates *HWB* values in a compile-time colour space.
+    pub fn new(h: f64, w: f64, b: f64) -> Self {
+        Hwb{h, w, b, space: S::default()}
+    }
+}
+
+impl<S> Color for Hwb<S> {}
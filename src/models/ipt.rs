@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,3 +13,134 @@
 // limitations under the License.
 
 //! # Ebner and Fairchild IPT color space
+//!
+//! *IPT* is designed for hue linearity, a constant hue angle keeping the perceived hue constant,
+//! which makes it suited to gradients. It is defined for *CIE XYZ* tristimulus values relative to
+//! *CIE Illuminant D65*.
+//!
+//! ## References
+//!
+//! [`Ebner & Fairchild (1998)` : Development and Testing of a Color Space (IPT) with Improved Hue Uniformity.](https://www.ingentaconnect.com/content/ist/cic/1998/00001998/00000001/art00003)
+
+use super::Color;
+use crate::adaptation::DEFAULT_TRANSFORM;
+use crate::colorimetry::illuminants::Illuminant;
+use crate::models::cie_lch::normalize_hue;
+use crate::models::cie_xyz::Xyz;
+
+use cgmath::*;
+
+const XYZ_TO_LMS_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.4002, y:-0.2280, z: 0.0000},
+    y: Vector3{x: 0.7075, y: 1.1500, z: 0.0000},
+    z: Vector3{x:-0.0807, y: 0.0612, z: 0.9184}
+};
+
+const LMS_TO_IPT_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.4000, y: 4.4550, z: 0.8056},
+    y: Vector3{x: 0.4000, y:-4.8510, z: 0.3572},
+    z: Vector3{x: 0.2000, y: 0.3960, z:-1.1628}
+};
+
+const IPT_TO_LMS_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.0, y: 1.0, z: 1.0},
+    y: Vector3{x: 0.09756893051461392, y:-0.11387648547314712, z: 0.03261510991706641},
+    z: Vector3{x: 0.2052264331645916, y: 0.13321715836999806, z:-0.6768871830691793}
+};
+
+const LMS_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.8502429449432056, y: 0.3668307751713486, z: 0.0},
+    y: Vector3{x:-1.138301637867233, y: 0.6438845448402355, z: 0.0},
+    z: Vector3{x: 0.2384349585087014, y:-0.010673443584379992, z: 1.088850174216028}
+};
+
+/// Exponent of the cone responses non-linearity.
+const EXPONENT: f64 = 0.43;
+
+/// Chroma under which a colour is considered achromatic and its hue undefined. The matrices are
+/// published with four decimals, so greys keep a chroma of about 1.6e-4 at the white point.
+pub const IPT_ACHROMATIC_THRESHOLD: f64 = 1e-3;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct Ipt {
+    pub i: f64,
+    pub p: f64,
+    pub t: f64,
+}
+
+impl Ipt {
+    /// Converts from *CIE XYZ* tristimulus values to *IPT* colourspace.
+    ///
+    /// *IPT* is defined for *CIE Illuminant D65*, other whites are adapted with the
+    /// [`DEFAULT_TRANSFORM`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::ipt::Ipt;
+    ///
+    /// let xyz = Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952};
+    /// let ipt = Ipt::from_xyz(&xyz, Illuminant::D65);
+    /// assert!((ipt.i - 0.3842619).abs() < 1e-7);
+    /// assert!((ipt.p - 0.3848731).abs() < 1e-7);
+    /// assert!((ipt.t - 0.1888684).abs() < 1e-7);
+    /// ```
+    pub fn from_xyz(xyz: &Xyz, white: Illuminant) -> Ipt {
+        let xyz = xyz.adapt(white, Illuminant::D65, DEFAULT_TRANSFORM);
+        let lms = XYZ_TO_LMS_MATRIX * Vector3{x: xyz.x, y: xyz.y, z: xyz.z};
+        let v = LMS_TO_IPT_MATRIX * lms.map(|c| c.signum() * c.abs().powf(EXPONENT));
+
+        Ipt{i: v.x, p: v.y, t: v.z}
+    }
+
+    /// Converts from *IPT* colourspace to *CIE XYZ* tristimulus values, adapted to `white`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::ipt::Ipt;
+    ///
+    /// let xyz = Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952};
+    /// let xyz_back = Ipt::from_xyz(&xyz, Illuminant::D50).to_xyz(Illuminant::D50);
+    /// assert!((xyz_back.x - xyz.x).abs() < 1e-9);
+    /// assert!((xyz_back.y - xyz.y).abs() < 1e-9);
+    /// assert!((xyz_back.z - xyz.z).abs() < 1e-9);
+    /// ```
+    pub fn to_xyz(&self, white: Illuminant) -> Xyz {
+        let lms = IPT_TO_LMS_MATRIX * Vector3{x: self.i, y: self.p, z: self.t};
+        let lms = lms.map(|c| c.signum() * c.abs().powf(1.0 / EXPONENT));
+        let v = LMS_TO_XYZ_MATRIX * lms;
+
+        Xyz{x: v.x, y: v.y, z: v.z}.adapt(Illuminant::D65, white, DEFAULT_TRANSFORM)
+    }
+
+    /// Returns the hue angle in degrees, `NaN` for achromatic colours as with
+    /// [`LchAb`](crate::models::cie_lch::LchAb), i.e. below [`IPT_ACHROMATIC_THRESHOLD`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::ipt::Ipt;
+    ///
+    /// assert!((Ipt{i: 0.5, p: 0.1, t: 0.1}.hue() - 45.0).abs() < 1e-9);
+    /// assert!(Ipt{i: 0.5, p: 0.0, t: 0.0}.hue().is_nan());
+    ///
+    /// let white = Ipt::from_xyz(&Xyz{x: 0.95047, y: 1.0, z: 1.08883}, Illuminant::D65);
+    /// assert!(white.hue().is_nan());
+    /// ```
+    pub fn hue(&self) -> f64 {
+        if self.p.hypot(self.t) < IPT_ACHROMATIC_THRESHOLD {
+            return f64::NAN;
+        }
+
+        normalize_hue(self.t.atan2(self.p).to_degrees())
+    }
+}
+
+impl Color for Ipt {}
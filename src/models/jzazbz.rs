@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,3 +13,251 @@
 // limitations under the License.
 
 //! # $J_za_zb_z$ color space
+//!
+//! *Jzazbz* is a perceptually uniform colour space for high dynamic range and wide gamut
+//! imagery, and *JzCzhz* its cylindrical representation.
+//!
+//! Unlike most colour spaces of the crate, it takes **absolute** *CIE XYZ* tristimulus values, in
+//! cd/m², relative to *CIE Illuminant D65*: a diffuse white of 100 cd/m² has a `Y` of 100, not 1.
+//! [`Jzazbz::from_absolute_xyz`] expects such values, [`Jzazbz::from_xyz`] scales and adapts
+//! relative tristimulus values for a given white luminance.
+//!
+//! ## References
+//!
+//! [`Safdar et al. (2017)` : Perceptually uniform color space for image signals including high dynamic range and wide gamut.](https://doi.org/10.1364/OE.25.015131)
+
+use super::Color;
+use crate::adaptation::DEFAULT_TRANSFORM;
+use crate::colorimetry::illuminants::Illuminant;
+use crate::models::cie_lch::{metric_hue_difference, to_polar, to_rectangular};
+use crate::models::cie_xyz::Xyz;
+
+use cgmath::*;
+
+const B: f64 = 1.15;
+const G: f64 = 0.66;
+const C1: f64 = 3424.0 / 4096.0;
+const C2: f64 = 2413.0 / 128.0;
+const C3: f64 = 2392.0 / 128.0;
+const N: f64 = 2610.0 / 16384.0;
+const P: f64 = 1.7 * 2523.0 / 32.0;
+const D: f64 = -0.56;
+const D0: f64 = 1.6295499532821566e-11;
+
+/// Luminance the cone responses are normalised by, in cd/m².
+const PEAK_LUMINANCE: f64 = 10000.0;
+
+/// Chroma, relative to the lightness *Jz*, under which a colour is considered achromatic and its
+/// hue undefined. The published matrices are rounded, so greys keep a chroma of up to about 0.4%
+/// of their lightness, e.g. 1.7e-4 for a *CIE Illuminant D65* white of 100 cd/m².
+pub const JZAZBZ_ACHROMATIC_THRESHOLD: f64 = 1e-2;
+
+const XYZ_TO_LMS_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.41478972, y:-0.2015100, z:-0.0166008},
+    y: Vector3{x: 0.579999, y: 1.120649, z: 0.264800},
+    z: Vector3{x: 0.0146480, y: 0.0531008, z: 0.6684799}
+};
+
+const LMS_TO_IZAZBZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.5, y: 3.524000, z: 0.199076},
+    y: Vector3{x: 0.5, y:-4.066708, z: 1.096799},
+    z: Vector3{x: 0.0, y: 0.542708, z:-1.295875}
+};
+
+const IZAZBZ_TO_LMS_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.0, y: 1.0, z: 1.0},
+    y: Vector3{x: 0.1386050432715393, y:-0.1386050432715393, z:-0.09601924202631895},
+    z: Vector3{x: 0.058047316156118876, y:-0.058047316156118876, z:-0.811891896056039}
+};
+
+const LMS_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.9242264357876067, y: 0.35031676209499907, z:-0.09098281098284758},
+    y: Vector3{x:-1.0047923125953655, y: 0.7264811939316552, z:-0.312728290523074},
+    z: Vector3{x: 0.03765140403061801, y:-0.06538442294808502, z: 1.5227665613052606}
+};
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct Jzazbz {
+    pub jz: f64,
+    pub az: f64,
+    pub bz: f64,
+}
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct Jzczhz {
+    pub jz: f64,
+    pub cz: f64,
+    pub hz: f64,
+}
+
+impl Jzazbz {
+    /// Converts from absolute *CIE XYZ* tristimulus values, in cd/m² and relative to
+    /// *CIE Illuminant D65*, to *Jzazbz* colourspace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::jzazbz::Jzazbz;
+    ///
+    /// let xyz = Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952};
+    /// let jzazbz = Jzazbz::from_absolute_xyz(&xyz);
+    /// assert!((jzazbz.jz - 0.0053504).abs() < 1e-7);
+    /// assert!((jzazbz.az - 0.0092430).abs() < 1e-7);
+    /// assert!((jzazbz.bz - 0.0052600).abs() < 1e-7);
+    /// ```
+    pub fn from_absolute_xyz(xyz: &Xyz) -> Jzazbz {
+        let x = B * xyz.x - (B - 1.0) * xyz.z;
+        let y = G * xyz.y - (G - 1.0) * xyz.x;
+        let lms = XYZ_TO_LMS_MATRIX * Vector3{x, y, z: xyz.z};
+        let lms = lms.map(|c| {
+            let c = (c / PEAK_LUMINANCE).max(0.0).powf(N);
+
+            ((C1 + C2 * c) / (1.0 + C3 * c)).powf(P)
+        });
+        let v = LMS_TO_IZAZBZ_MATRIX * lms;
+
+        Jzazbz{jz: (1.0 + D) * v.x / (1.0 + D * v.x) - D0, az: v.y, bz: v.z}
+    }
+
+    /// Converts from *Jzazbz* colourspace to absolute *CIE XYZ* tristimulus values, in cd/m² and
+    /// relative to *CIE Illuminant D65*.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::jzazbz::Jzazbz;
+    ///
+    /// let xyz = Xyz{x: 95.047, y: 100.0, z: 108.883};
+    /// let xyz_back = Jzazbz::from_absolute_xyz(&xyz).to_absolute_xyz();
+    /// assert!((xyz_back.x - xyz.x).abs() < 1e-6);
+    /// assert!((xyz_back.y - xyz.y).abs() < 1e-6);
+    /// assert!((xyz_back.z - xyz.z).abs() < 1e-6);
+    /// ```
+    pub fn to_absolute_xyz(&self) -> Xyz {
+        let jz = self.jz + D0;
+        let iz = jz / (1.0 + D - D * jz);
+        let lms = IZAZBZ_TO_LMS_MATRIX * Vector3{x: iz, y: self.az, z: self.bz};
+        let lms = lms.map(|c| {
+            let c = c.powf(1.0 / P);
+
+            PEAK_LUMINANCE * ((C1 - c) / (C3 * c - C2)).max(0.0).powf(1.0 / N)
+        });
+        let v = LMS_TO_XYZ_MATRIX * lms;
+
+        let x = (v.x + (B - 1.0) * v.z) / B;
+        let y = (v.y + (G - 1.0) * x) / G;
+
+        Xyz{x, y, z: v.z}
+    }
+
+    /// Converts from relative *CIE XYZ* tristimulus values to *Jzazbz* colourspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - *CIE XYZ* tristimulus values, a `Y` of 1 being the white.
+    /// * `white` - illuminant the tristimulus values are relative to, adapted to
+    ///   *CIE Illuminant D65* with the [`DEFAULT_TRANSFORM`].
+    /// * `white_luminance` - absolute luminance of the white, in cd/m².
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::jzazbz::Jzazbz;
+    ///
+    /// let xyz = Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952};
+    /// let absolute = Xyz{x: 0.20654008 * 100.0, y: 0.12197225 * 100.0, z: 0.05136952 * 100.0};
+    /// assert_eq!(Jzazbz::from_xyz(&xyz, Illuminant::D65, 100.0), Jzazbz::from_absolute_xyz(&absolute));
+    /// ```
+    pub fn from_xyz(xyz: &Xyz, white: Illuminant, white_luminance: f64) -> Jzazbz {
+        let xyz = xyz.adapt(white, Illuminant::D65, DEFAULT_TRANSFORM);
+
+        Jzazbz::from_absolute_xyz(&Xyz{
+            x: xyz.x * white_luminance,
+            y: xyz.y * white_luminance,
+            z: xyz.z * white_luminance,
+        })
+    }
+
+    /// Converts from *Jzazbz* colourspace to relative *CIE XYZ* tristimulus values, adapted to
+    /// `white`, for a white of the given absolute luminance in cd/m².
+    pub fn to_xyz(&self, white: Illuminant, white_luminance: f64) -> Xyz {
+        let xyz = self.to_absolute_xyz();
+
+        Xyz{
+            x: xyz.x / white_luminance,
+            y: xyz.y / white_luminance,
+            z: xyz.z / white_luminance,
+        }
+        .adapt(Illuminant::D65, white, DEFAULT_TRANSFORM)
+    }
+}
+
+impl Jzczhz {
+    /// Converts from *Jzazbz* colourspace to *JzCzhz* colourspace, the hue being in degrees and
+    /// `NaN` for achromatic colours as with [`LchAb`](crate::models::cie_lch::LchAb), i.e. below
+    /// [`JZAZBZ_ACHROMATIC_THRESHOLD`] times *Jz*.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::jzazbz::{Jzazbz, Jzczhz};
+    ///
+    /// let jzczhz = Jzczhz::from_jzazbz(&Jzazbz{jz: 0.1, az: 0.01, bz: -0.01});
+    /// assert!((jzczhz.cz - 0.0141421).abs() < 1e-7 && jzczhz.hz == 315.0);
+    ///
+    /// let jzazbz = jzczhz.to_jzazbz();
+    /// assert!((jzazbz.az - 0.01).abs() < 1e-12 && (jzazbz.bz + 0.01).abs() < 1e-12);
+    ///
+    /// let white = Jzazbz::from_xyz(&Xyz{x: 0.95047, y: 1.0, z: 1.08883}, Illuminant::D65, 100.0);
+    /// assert!(Jzczhz::from_jzazbz(&white).hz.is_nan());
+    /// assert!(Jzczhz::from_jzazbz(&Jzazbz::default()).hz.is_nan());
+    /// ```
+    pub fn from_jzazbz(jzazbz: &Jzazbz) -> Jzczhz {
+        let threshold = (JZAZBZ_ACHROMATIC_THRESHOLD * jzazbz.jz.abs()).max(f64::EPSILON);
+        let (cz, hz) = to_polar(jzazbz.az, jzazbz.bz, threshold);
+
+        Jzczhz{jz: jzazbz.jz, cz, hz}
+    }
+
+    /// Converts from *JzCzhz* colourspace to *Jzazbz* colourspace.
+    pub fn to_jzazbz(&self) -> Jzazbz {
+        let (az, bz) = to_rectangular(self.cz, self.hz);
+
+        Jzazbz{jz: self.jz, az, bz}
+    }
+}
+
+impl Color for Jzazbz {}
+
+impl Color for Jzczhz {}
+
+/// Returns the ΔEz colour difference between two *Jzazbz* colours.
+///
+/// # Example
+///
+/// ```
+/// use pigments::models::jzazbz::{delta_e_z, Jzazbz};
+///
+/// let a = Jzazbz{jz: 0.1, az: 0.01, bz: 0.0};
+/// let b = Jzazbz{jz: 0.1, az: 0.0, bz: 0.01};
+/// assert!((delta_e_z(&a, &b) - 0.0141421).abs() < 1e-7);
+/// assert_eq!(delta_e_z(&a, &a), 0.0);
+///
+/// // Greys have no hue, so their residual chroma does not add a hue difference.
+/// let (c, d) = (Jzazbz{jz: 0.1, az: 1e-4, bz: 0.0}, Jzazbz{jz: 0.1, az: 0.0, bz: 1e-4});
+/// assert_eq!(delta_e_z(&c, &d), 0.0);
+/// ```
+pub fn delta_e_z(a: &Jzazbz, b: &Jzazbz) -> f64 {
+    let (a, b) = (Jzczhz::from_jzazbz(a), Jzczhz::from_jzazbz(b));
+    let dj = a.jz - b.jz;
+    let dc = a.cz - b.cz;
+    let dh = metric_hue_difference(a.cz, a.hz, b.cz, b.hz);
+
+    (dj * dj + dc * dc + dh * dh).sqrt()
+}
@@ -14,6 +14,7 @@
 
 pub mod bt2020;
 pub mod cie_lab;
+pub mod cie_lch;
 pub mod cie_luv;
 pub mod cie_ucs;
 pub mod cie_uvw;
@@ -21,17 +22,28 @@ pub mod cie_xyy;
 pub mod cie_xyz;
 pub mod cmy;
 pub mod cmyk;
+pub mod din99;
 pub mod hex;
 pub mod hsl;
+pub mod hsluv;
 pub mod hsv;
+pub mod hunter_lab;
+pub mod hunter_rdab;
+pub mod hwb;
 pub mod ipt;
 pub mod jzazbz;
+pub mod okhsl;
+pub mod okhsv;
+pub mod oklab;
+pub mod osa_ucs;
 pub mod rgb;
 pub mod spectral;
+pub mod ycbcr;
 pub mod yiq;
 pub mod yuv;
 
 use cgmath::*;
+use crate::colorimetry::illuminants::{chromaticity, Illuminant, StandardObserver};
 use crate::models::cie_xyz::Xyz;
 use crate::models::cie_xyy::Xyy;
 
@@ -106,3 +118,107 @@ pub fn xy_to_xyz(xy: &Vector2<f64>) -> Xyz {
 pub fn xy_to_xyy(xy: &Vector2<f64>, y: f64) -> Xyy {
     Xyy{x: xy.x, y1:xy.y, y2: y}
 }
+
+/// Returns the *CIE XYZ* tristimulus values of an *illuminant* white, normalised so that `Y` is 1.
+///
+/// # Arguments
+///
+/// * `o` - standard observer.
+/// * `i` - illuminant.
+///
+/// # Returns
+///
+/// *CIE XYZ* tristimulus values.
+///
+/// # Example
+///
+/// ```
+/// use pigments::colorimetry::illuminants::{Illuminant, StandardObserver};
+/// use pigments::models::illuminant_to_xyz;
+///
+/// let d65 = illuminant_to_xyz(StandardObserver::Two, Illuminant::D65);
+/// assert!((d65.x - 0.9504559).abs() < 1e-7 && d65.y == 1.0 && (d65.z - 1.0890578).abs() < 1e-7);
+/// ```
+pub fn illuminant_to_xyz(o: StandardObserver, i: Illuminant) -> Xyz {
+    xy_to_xyz(&chromaticity::illuminant(o, i))
+}
+
+/// Converts from *CIE xy* chromaticity coordinates to *CIE 1960 UCS* uv chromaticity coordinates.
+///
+/// # Example
+///
+/// ```
+/// use cgmath::Vector2;
+/// use pigments::models::xy_to_uv;
+///
+/// let uv = xy_to_uv(&Vector2{x: 0.54369557, y: 0.32107944});
+/// assert!((uv.x - 0.37720213).abs() < 1e-7 && (uv.y - 0.33413508).abs() < 1e-7);
+/// ```
+pub fn xy_to_uv(xy: &Vector2<f64>) -> Vector2<f64> {
+    let d = -2.0 * xy.x + 12.0 * xy.y + 3.0;
+
+    Vector2{x: 4.0 * xy.x / d, y: 6.0 * xy.y / d}
+}
+
+/// Converts from *CIE 1960 UCS* uv chromaticity coordinates to *CIE xy* chromaticity coordinates.
+///
+/// # Example
+///
+/// ```
+/// use cgmath::Vector2;
+/// use pigments::models::uv_to_xy;
+///
+/// let xy = uv_to_xy(&Vector2{x: 0.37720213, y: 0.33413508});
+/// assert!((xy.x - 0.54369557).abs() < 1e-7 && (xy.y - 0.32107944).abs() < 1e-7);
+/// ```
+pub fn uv_to_xy(uv: &Vector2<f64>) -> Vector2<f64> {
+    let d = 2.0 * uv.x - 8.0 * uv.y + 4.0;
+
+    Vector2{x: 3.0 * uv.x / d, y: 2.0 * uv.y / d}
+}
+
+/// Converts from *CIE xy* chromaticity coordinates to *CIE 1976 UCS* u'v' chromaticity
+/// coordinates.
+///
+/// # Example
+///
+/// ```
+/// use cgmath::Vector2;
+/// use pigments::models::xy_to_uv_prime;
+///
+/// let uv = xy_to_uv_prime(&Vector2{x: 0.54369557, y: 0.32107944});
+/// assert!((uv.x - 0.37720213).abs() < 1e-7 && (uv.y - 0.50120262).abs() < 1e-7);
+/// ```
+pub fn xy_to_uv_prime(xy: &Vector2<f64>) -> Vector2<f64> {
+    uv_to_uv_prime(&xy_to_uv(xy))
+}
+
+/// Converts from *CIE 1976 UCS* u'v' chromaticity coordinates to *CIE xy* chromaticity
+/// coordinates.
+///
+/// # Example
+///
+/// ```
+/// use cgmath::Vector2;
+/// use pigments::models::uv_prime_to_xy;
+///
+/// let xy = uv_prime_to_xy(&Vector2{x: 0.37720213, y: 0.50120262});
+/// assert!((xy.x - 0.54369557).abs() < 1e-7 && (xy.y - 0.32107944).abs() < 1e-7);
+/// ```
+pub fn uv_prime_to_xy(uv: &Vector2<f64>) -> Vector2<f64> {
+    uv_to_xy(&uv_prime_to_uv(uv))
+}
+
+/// Converts from *CIE 1960 UCS* uv chromaticity coordinates to *CIE 1976 UCS* u'v' chromaticity
+/// coordinates.
+#[inline]
+pub fn uv_to_uv_prime(uv: &Vector2<f64>) -> Vector2<f64> {
+    Vector2{x: uv.x, y: 1.5 * uv.y}
+}
+
+/// Converts from *CIE 1976 UCS* u'v' chromaticity coordinates to *CIE 1960 UCS* uv chromaticity
+/// coordinates.
+#[inline]
+pub fn uv_prime_to_uv(uv: &Vector2<f64>) -> Vector2<f64> {
+    Vector2{x: uv.x, y: uv.y / 1.5}
+}
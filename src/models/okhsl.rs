@@ -0,0 +1,163 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Okhsl color space
+//!
+//! Hue, saturation and lightness model built on [`Oklab`](crate::models::oklab::Oklab), with an
+//! even lightness scale and the saturation normalised to the *sRGB* gamut, so that it can be used
+//! in colour pickers like *HSL*.
+//!
+//! Hues follow the [`Hsl`](crate::models::hsl::Hsl) conventions: degrees, `NaN` for achromatic
+//! colours and read as 0 when converting back.
+//!
+//! ## References
+//!
+//! [`Ottosson (2021)` : Two new color spaces for color picking - Okhsv and Okhsl.](https://bottosson.github.io/posts/colorpicker/)
+
+use super::Color;
+use crate::models::cie_lch::normalize_hue;
+use crate::models::oklab::{find_cusp, find_gamut_intersection, to_st, toe, toe_inv, Oklab, OKLAB_ACHROMATIC_THRESHOLD};
+use crate::models::rgb::color_space::Srgb;
+use crate::models::rgb::Rgb;
+
+/// Saturation the mid chroma is mapped to.
+const MID: f64 = 0.8;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct Okhsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
+impl Okhsl {
+    /// Converts from *sRGB* values to *Okhsl* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::okhsl::Okhsl;
+    /// use pigments::models::rgb::color_space::Srgb;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let okhsl = Okhsl::from_rgb(&Rgb::<Srgb>::new(1.0, 0.0, 0.0));
+    /// assert!((okhsl.h - 29.2338851).abs() < 1e-6 && (okhsl.s - 1.0).abs() < 1e-4);
+    /// assert!((okhsl.l - 0.5680846).abs() < 1e-6);
+    ///
+    /// let grey = Okhsl::from_rgb(&Rgb::<Srgb>::new(0.5, 0.5, 0.5));
+    /// assert!(grey.h.is_nan() && grey.s == 0.0);
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<Srgb>) -> Okhsl {
+        let lab = Oklab::from_linear_rgb(&rgb.linearize());
+        let c = lab.a.hypot(lab.b);
+        let l = toe(lab.l);
+
+        if c < OKLAB_ACHROMATIC_THRESHOLD || lab.l <= 0.0 || lab.l >= 1.0 {
+            return Okhsl{h: f64::NAN, s: 0.0, l};
+        }
+
+        let (a_, b_) = (lab.a / c, lab.b / c);
+        let (c_0, c_mid, c_max) = chromas(lab.l, a_, b_);
+
+        let s = if c < c_mid {
+            let k1 = MID * c_0;
+            let k2 = 1.0 - k1 / c_mid;
+
+            MID * c / (k1 + k2 * c)
+        } else {
+            let k1 = (1.0 - MID) * c_mid * c_mid / (MID * MID * c_0);
+            let k2 = 1.0 - k1 / (c_max - c_mid);
+
+            MID + (1.0 - MID) * (c - c_mid) / (k1 + k2 * (c - c_mid))
+        };
+
+        Okhsl{h: normalize_hue(lab.b.atan2(lab.a).to_degrees()), s, l}
+    }
+
+    /// Converts from *Okhsl* values to *sRGB* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::okhsl::Okhsl;
+    ///
+    /// let rgb = Okhsl{h: 29.2338851, s: 1.0, l: 0.5680846}.to_rgb();
+    /// assert!((rgb.r - 1.0).abs() < 1e-4 && rgb.g.abs() < 1e-4 && rgb.b.abs() < 1e-4);
+    ///
+    /// let white = Okhsl{h: f64::NAN, s: 0.0, l: 1.0}.to_rgb();
+    /// assert!(white.r == 1.0 && white.g == 1.0 && white.b == 1.0);
+    /// ```
+    pub fn to_rgb(&self) -> Rgb<Srgb> {
+        if self.l >= 1.0 {
+            return Rgb::new(1.0, 1.0, 1.0);
+        }
+        if self.l <= 0.0 {
+            return Rgb::new(0.0, 0.0, 0.0);
+        }
+
+        let h = if self.h.is_nan() { 0.0 } else { self.h.to_radians() };
+        let (a_, b_) = (h.cos(), h.sin());
+        let l = toe_inv(self.l);
+        let (c_0, c_mid, c_max) = chromas(l, a_, b_);
+
+        let c = if self.s < MID {
+            let t = self.s / MID;
+            let k1 = MID * c_0;
+            let k2 = 1.0 - k1 / c_mid;
+
+            t * k1 / (1.0 - k2 * t)
+        } else {
+            let t = (self.s - MID) / (1.0 - MID);
+            let k1 = (1.0 - MID) * c_mid * c_mid / (MID * MID * c_0);
+            let k2 = 1.0 - k1 / (c_max - c_mid);
+
+            c_mid + t * k1 / (1.0 - k2 * t)
+        };
+
+        Oklab{l, a: c * a_, b: c * b_}.to_linear_rgb().encode()
+    }
+}
+
+impl Color for Okhsl {}
+
+/// Returns the chromas `(C0, Cmid, Cmax)` the saturation is interpolated between, for a lightness
+/// and a normalised hue direction `(a, b)`.
+fn chromas(l: f64, a: f64, b: f64) -> (f64, f64, f64) {
+    let cusp = find_cusp(a, b);
+    let c_max = find_gamut_intersection(a, b, l, 1.0, l, cusp);
+    let (s_max, t_max) = to_st(cusp);
+
+    // Scale factor compensating for the curved part of the gamut shape.
+    let k = c_max / (l * s_max).min((1.0 - l) * t_max);
+
+    let (s_mid, t_mid) = st_mid(a, b);
+    let (c_a, c_b) = (l * s_mid, (1.0 - l) * t_mid);
+    let c_mid = 0.9 * k * (1.0 / (1.0 / c_a.powi(4) + 1.0 / c_b.powi(4))).sqrt().sqrt();
+
+    let (c_a, c_b) = (l * 0.4, (1.0 - l) * 0.8);
+    let c_0 = (1.0 / (1.0 / (c_a * c_a) + 1.0 / (c_b * c_b))).sqrt();
+
+    (c_0, c_mid, c_max)
+}
+
+/// Returns a smooth approximation of the slopes `(S, T)` of the cusp triangle, used to keep the
+/// mid saturation regular across hues.
+fn st_mid(a: f64, b: f64) -> (f64, f64) {
+    let s = 0.11516993 + 1.0 / (7.44778970 + 4.15901240 * b + a * (-2.19557347 + 1.75198401 * b
+        + a * (-2.13704948 - 10.02301043 * b + a * (-4.24894561 + 5.38770819 * b + 4.69891013 * a))));
+    let t = 0.11239642 + 1.0 / (1.61320320 - 0.68124379 * b + a * (0.40370612 + 0.90148123 * b
+        + a * (-0.27087943 + 0.61223990 * b + a * (0.00299215 - 0.45399568 * b - 0.14661872 * a))));
+
+    (s, t)
+}
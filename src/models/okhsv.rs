@@ -0,0 +1,138 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Okhsv color space
+//!
+//! Hue, saturation and value model built on [`Oklab`](crate::models::oklab::Oklab), with the
+//! saturation and value normalised to the *sRGB* gamut, so that it can be used in colour pickers
+//! like *HSV*.
+//!
+//! Hues follow the [`Hsv`](crate::models::hsv::Hsv) conventions: degrees, `NaN` for achromatic
+//! colours and read as 0 when converting back.
+//!
+//! ## References
+//!
+//! [`Ottosson (2021)` : Two new color spaces for color picking - Okhsv and Okhsl.](https://bottosson.github.io/posts/colorpicker/)
+
+use super::Color;
+use crate::models::cie_lch::normalize_hue;
+use crate::models::oklab::{find_cusp, to_st, toe, toe_inv, Oklab, OKLAB_ACHROMATIC_THRESHOLD};
+use crate::models::rgb::color_space::Srgb;
+use crate::models::rgb::Rgb;
+
+/// Saturation of the triangle approximating the gamut at its lowest value.
+const S_0: f64 = 0.5;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct Okhsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
+
+impl Okhsv {
+    /// Converts from *sRGB* values to *Okhsv* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::okhsv::Okhsv;
+    /// use pigments::models::rgb::color_space::Srgb;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let okhsv = Okhsv::from_rgb(&Rgb::<Srgb>::new(0.2, 0.4, 0.6));
+    /// assert!((okhsv.h - 250.4330537).abs() < 1e-6);
+    /// assert!((okhsv.s - 0.7084265).abs() < 1e-7 && (okhsv.v - 0.6156298).abs() < 1e-7);
+    ///
+    /// let grey = Okhsv::from_rgb(&Rgb::<Srgb>::new(0.5, 0.5, 0.5));
+    /// assert!(grey.h.is_nan() && grey.s == 0.0);
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<Srgb>) -> Okhsv {
+        let lab = Oklab::from_linear_rgb(&rgb.linearize());
+        let c = lab.a.hypot(lab.b);
+
+        if c < OKLAB_ACHROMATIC_THRESHOLD || lab.l <= 0.0 {
+            return Okhsv{h: f64::NAN, s: 0.0, v: toe(lab.l)};
+        }
+
+        let (a_, b_) = (lab.a / c, lab.b / c);
+        let (s_max, t_max) = to_st(find_cusp(a_, b_));
+        let k = 1.0 - S_0 / s_max;
+
+        // Position along the line from the colour to the white point, projected on the boundary.
+        let t = t_max / (c + lab.l * t_max);
+        let (l_v, c_v) = (t * lab.l, t * c);
+
+        // Compensates for the curved upper part of the gamut and for the toe.
+        let l_vt = toe_inv(l_v);
+        let c_vt = c_v * l_vt / l_v;
+        let l = toe(lab.l / scale(l_vt, c_vt, a_, b_));
+
+        Okhsv{
+            h: normalize_hue(lab.b.atan2(lab.a).to_degrees()),
+            s: (S_0 + t_max) * c_v / (t_max * S_0 + t_max * k * c_v),
+            v: l / l_v,
+        }
+    }
+
+    /// Converts from *Okhsv* values to *sRGB* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::okhsv::Okhsv;
+    ///
+    /// let rgb = Okhsv{h: 250.4330537, s: 0.7084265, v: 0.6156298}.to_rgb();
+    /// assert!((rgb.r - 0.2).abs() < 1e-5 && (rgb.g - 0.4).abs() < 1e-5 && (rgb.b - 0.6).abs() < 1e-5);
+    ///
+    /// let grey = Okhsv{h: f64::NAN, s: 0.0, v: 0.5}.to_rgb();
+    /// assert!((grey.r - grey.g).abs() < 1e-7 && (grey.g - grey.b).abs() < 1e-7);
+    /// ```
+    pub fn to_rgb(&self) -> Rgb<Srgb> {
+        if self.v <= 0.0 {
+            return Rgb::new(0.0, 0.0, 0.0);
+        }
+
+        let h = if self.h.is_nan() { 0.0 } else { self.h.to_radians() };
+        let (a_, b_) = (h.cos(), h.sin());
+        let (s_max, t_max) = to_st(find_cusp(a_, b_));
+        let k = 1.0 - S_0 / s_max;
+
+        let d = S_0 + t_max - t_max * k * self.s;
+        let l_v = 1.0 - self.s * S_0 / d;
+        let c_v = self.s * t_max * S_0 / d;
+
+        let (l, c) = (self.v * l_v, self.v * c_v);
+
+        // Compensates for the toe and for the curved upper part of the gamut.
+        let l_vt = toe_inv(l_v);
+        let c_vt = c_v * l_vt / l_v;
+        let l_new = toe_inv(l);
+        let c = c * l_new / l;
+        let scale_l = scale(l_vt, c_vt, a_, b_);
+        let (l, c) = (l_new * scale_l, c * scale_l);
+
+        Oklab{l, a: c * a_, b: c * b_}.to_linear_rgb().encode()
+    }
+}
+
+impl Color for Okhsv {}
+
+/// Returns the factor scaling a colour on the triangle approximating the gamut onto its curved
+/// upper boundary.
+fn scale(l: f64, c: f64, a: f64, b: f64) -> f64 {
+    let rgb = Oklab{l, a: a * c, b: b * c}.to_linear_rgb();
+
+    (1.0 / rgb.r.max(rgb.g).max(rgb.b).max(0.0)).cbrt()
+}
@@ -0,0 +1,297 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Oklab color space
+//!
+//! *Oklab* is a perceptual colour space predicting lightness, chroma and hue, designed for image
+//! processing, and *Oklch* its cylindrical representation. *CSS Color 4* uses them for
+//! interpolation and gamut mapping. It is defined relative to *CIE Illuminant D65*.
+//!
+//! ## References
+//!
+//! [`Ottosson (2020)` : A perceptual color space for image processing.](https://bottosson.github.io/posts/oklab/)
+
+use super::Color;
+use crate::adaptation::DEFAULT_TRANSFORM;
+use crate::colorimetry::illuminants::Illuminant;
+use crate::models::cie_lch::{to_polar, to_rectangular};
+use crate::models::cie_xyz::Xyz;
+use crate::models::rgb::color_space::Srgb;
+use crate::models::rgb::LinearRgb;
+
+use cgmath::*;
+
+/// *CSS Color 4* re-derivation of the *CIE XYZ* to cone responses matrix, mapping the
+/// *CIE Illuminant D65* white exactly to equal cone responses.
+const XYZ_TO_LMS_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.819022437996703, y: 0.0329836539323885, z: 0.0481771893596242},
+    y: Vector3{x: 0.3619062600528904, y: 0.9292868615863434, z: 0.2642395317527308},
+    z: Vector3{x:-0.1288737815209879, y: 0.0361446663506424, z: 0.6335478284694309}
+};
+
+const LMS_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.2268798758459243, y:-0.0405757452148008, z:-0.0763729366746601},
+    y: Vector3{x:-0.5578149944602171, y: 1.112286803280317, z:-0.4214933324022432},
+    z: Vector3{x: 0.2813910456659647, y:-0.0717110580655164, z: 1.5869240198367816}
+};
+
+const SRGB_TO_LMS_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.4122214708, y: 0.2119034982, z: 0.0883024619},
+    y: Vector3{x: 0.5363325363, y: 0.6806995451, z: 0.2817188376},
+    z: Vector3{x: 0.0514459929, y: 0.1073969566, z: 0.6299787005}
+};
+
+const LMS_TO_SRGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 4.0767416621, y:-1.2684380046, z:-0.0041960863},
+    y: Vector3{x:-3.3077115913, y: 2.6097574011, z:-0.7034186147},
+    z: Vector3{x: 0.2309699292, y:-0.3413193965, z: 1.7076147010}
+};
+
+const LMS_TO_OKLAB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.2104542553, y: 1.9779984951, z: 0.0259040371},
+    y: Vector3{x: 0.7936177850, y:-2.4285922050, z: 0.7827717662},
+    z: Vector3{x:-0.0040720468, y: 0.4505937099, z:-0.8086757660}
+};
+
+const OKLAB_TO_LMS_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.9999999984505198, y: 1.0000000088817609, z: 1.0000000546724108},
+    y: Vector3{x: 0.39633779217376786, y:-0.10556134232365635, z:-0.08948418209496575},
+    z: Vector3{x: 0.2158037580607588, y:-0.06385417477170591, z:-1.2914855378640917}
+};
+
+/// Chroma below which a colour is considered achromatic. The published cone responses to *Oklab*
+/// matrix is rounded to 10 digits, which leaves greys with a residual chroma of about `4e-8`, from
+/// linear *sRGB* values as well as from *CIE XYZ* tristimulus values. Greys of colour spaces with
+/// rounded matrices keep more, e.g. `7e-6` for *Adobe RGB (1998)*, which is still far below a
+/// visible chroma.
+pub(crate) const OKLAB_ACHROMATIC_THRESHOLD: f64 = 1e-4;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct Oklab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct Oklch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl Oklab {
+    /// Converts from linear *sRGB* values to *Oklab* colourspace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::oklab::Oklab;
+    /// use pigments::models::rgb::color_space::Srgb;
+    /// use pigments::models::rgb::LinearRgb;
+    ///
+    /// let oklab = Oklab::from_linear_rgb(&LinearRgb::<Srgb>::new(1.0, 0.0, 0.0));
+    /// assert!((oklab.l - 0.6279554).abs() < 1e-7);
+    /// assert!((oklab.a - 0.2248631).abs() < 1e-7);
+    /// assert!((oklab.b - 0.1258463).abs() < 1e-7);
+    ///
+    /// let rgb = oklab.to_linear_rgb();
+    /// assert!((rgb.r - 1.0).abs() < 1e-7 && rgb.g.abs() < 1e-7 && rgb.b.abs() < 1e-7);
+    /// ```
+    pub fn from_linear_rgb(rgb: &LinearRgb<Srgb>) -> Oklab {
+        Oklab::from_lms(SRGB_TO_LMS_MATRIX * Vector3{x: rgb.r, y: rgb.g, z: rgb.b})
+    }
+
+    /// Converts from *Oklab* colourspace to linear *sRGB* values.
+    pub fn to_linear_rgb(&self) -> LinearRgb<Srgb> {
+        let v = LMS_TO_SRGB_MATRIX * self.lms();
+
+        LinearRgb::new(v.x, v.y, v.z)
+    }
+
+    /// Converts from *CIE XYZ* tristimulus values to *Oklab* colourspace.
+    ///
+    /// Whites other than *CIE Illuminant D65* are adapted with the [`DEFAULT_TRANSFORM`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::oklab::{Oklab, Oklch};
+    ///
+    /// let xyz = Xyz{x: 0.950, y: 1.0, z: 1.089};
+    /// let oklab = Oklab::from_xyz(&xyz, Illuminant::D65);
+    /// assert!((oklab.l - 1.0).abs() < 1e-3 && oklab.a.abs() < 1e-3 && oklab.b.abs() < 1e-3);
+    ///
+    /// let white = Oklab::from_xyz(&Xyz{x: 0.3127 / 0.3290, y: 1.0, z: 0.3583 / 0.3290}, Illuminant::D65);
+    /// assert!((white.l - 1.0).abs() < 1e-7 && Oklch::from_oklab(&white).h.is_nan());
+    ///
+    /// let xyz_back = oklab.to_xyz(Illuminant::D65);
+    /// assert!((xyz_back.x - 0.950).abs() < 1e-9 && (xyz_back.y - 1.0).abs() < 1e-9);
+    /// assert!((xyz_back.z - 1.089).abs() < 1e-9);
+    /// ```
+    pub fn from_xyz(xyz: &Xyz, white: Illuminant) -> Oklab {
+        let xyz = xyz.adapt(white, Illuminant::D65, DEFAULT_TRANSFORM);
+
+        Oklab::from_lms(XYZ_TO_LMS_MATRIX * Vector3{x: xyz.x, y: xyz.y, z: xyz.z})
+    }
+
+    /// Converts from *Oklab* colourspace to *CIE XYZ* tristimulus values, adapted to `white`.
+    pub fn to_xyz(&self, white: Illuminant) -> Xyz {
+        let v = LMS_TO_XYZ_MATRIX * self.lms();
+
+        Xyz{x: v.x, y: v.y, z: v.z}.adapt(Illuminant::D65, white, DEFAULT_TRANSFORM)
+    }
+
+    fn from_lms(lms: Vector3<f64>) -> Oklab {
+        let v = LMS_TO_OKLAB_MATRIX * lms.map(f64::cbrt);
+
+        Oklab{l: v.x, a: v.y, b: v.z}
+    }
+
+    fn lms(&self) -> Vector3<f64> {
+        (OKLAB_TO_LMS_MATRIX * Vector3{x: self.l, y: self.a, z: self.b}).map(|c| c * c * c)
+    }
+}
+
+impl Oklch {
+    /// Converts from *Oklab* colourspace to *Oklch* colourspace, the hue being in degrees and `NaN`
+    /// for achromatic colours as with [`LchAb`](crate::models::cie_lch::LchAb).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::oklab::{Oklab, Oklch};
+    ///
+    /// let oklch = Oklch::from_oklab(&Oklab{l: 0.6279554, a: 0.2248631, b: 0.1258463});
+    /// assert!((oklch.c - 0.2576833).abs() < 1e-7 && (oklch.h - 29.2338812).abs() < 1e-6);
+    ///
+    /// let oklab = oklch.to_oklab();
+    /// assert!((oklab.a - 0.2248631).abs() < 1e-12 && (oklab.b - 0.1258463).abs() < 1e-12);
+    /// ```
+    pub fn from_oklab(oklab: &Oklab) -> Oklch {
+        let (c, h) = to_polar(oklab.a, oklab.b, OKLAB_ACHROMATIC_THRESHOLD);
+
+        Oklch{l: oklab.l, c, h}
+    }
+
+    /// Converts from *Oklch* colourspace to *Oklab* colourspace.
+    pub fn to_oklab(&self) -> Oklab {
+        let (a, b) = to_rectangular(self.c, self.h);
+
+        Oklab{l: self.l, a, b}
+    }
+}
+
+impl Color for Oklab {}
+
+impl Color for Oklch {}
+
+/// Toe function mapping *Oklab* lightness to the lightness estimate used by *Okhsl* and *Okhsv*.
+pub(crate) fn toe(x: f64) -> f64 {
+    let (k1, k2) = (0.206, 0.03);
+    let k3 = (1.0 + k1) / (1.0 + k2);
+    let t = k3 * x - k1;
+
+    0.5 * (t + (t * t + 4.0 * k2 * k3 * x).sqrt())
+}
+
+/// Inverse of [`toe`].
+pub(crate) fn toe_inv(x: f64) -> f64 {
+    let (k1, k2) = (0.206, 0.03);
+    let k3 = (1.0 + k1) / (1.0 + k2);
+
+    (x * x + k1 * x) / (k3 * (x + k2))
+}
+
+/// Returns the maximum saturation `S = C / L` of the *sRGB* gamut for a normalised hue
+/// direction `(a, b)`.
+fn max_saturation(a: f64, b: f64) -> f64 {
+    // The saturation is limited by the first component reaching 0, each has its own polynomial fit.
+    let (k, w) = if -1.88170328 * a - 0.80936493 * b > 1.0 {
+        ([1.19086277, 1.76576728, 0.59662641, 0.75515197, 0.56771245], LMS_TO_SRGB_MATRIX.row(0))
+    } else if 1.81444104 * a - 1.19445276 * b > 1.0 {
+        ([0.73956515, -0.45954404, 0.08285427, 0.12541070, 0.14503204], LMS_TO_SRGB_MATRIX.row(1))
+    } else {
+        ([1.35733652, -0.00915799, -1.15130210, -0.50559606, 0.00692167], LMS_TO_SRGB_MATRIX.row(2))
+    };
+    let s = k[0] + k[1] * a + k[2] * b + k[3] * a * a + k[4] * a * b;
+
+    // One step of Halley's method refines the approximation.
+    let k_lms = OKLAB_TO_LMS_MATRIX * Vector3{x: 0.0, y: a, z: b};
+    let lms_ = k_lms.map(|k| 1.0 + s * k);
+    let lms = lms_.map(|c| c * c * c);
+    let lms_ds = k_lms.mul_element_wise(lms_).mul_element_wise(lms_) * 3.0;
+    let lms_ds2 = k_lms.mul_element_wise(k_lms).mul_element_wise(lms_) * 6.0;
+
+    let (f, f1, f2) = (w.dot(lms), w.dot(lms_ds), w.dot(lms_ds2));
+
+    s - f * f1 / (f1 * f1 - 0.5 * f * f2)
+}
+
+/// Returns the lightness and chroma `(L, C)` of the cusp of the *sRGB* gamut for a normalised hue
+/// direction `(a, b)`.
+pub(crate) fn find_cusp(a: f64, b: f64) -> (f64, f64) {
+    let s = max_saturation(a, b);
+    let rgb = Oklab{l: 1.0, a: s * a, b: s * b}.to_linear_rgb();
+    let l = (1.0 / rgb.r.max(rgb.g).max(rgb.b)).cbrt();
+
+    (l, l * s)
+}
+
+/// Returns the `t` for which the line `L = L0 (1 - t) + t L1, C = t C1` intersects the boundary of
+/// the *sRGB* gamut, for a normalised hue direction `(a, b)`.
+pub(crate) fn find_gamut_intersection(a: f64, b: f64, l1: f64, c1: f64, l0: f64, cusp: (f64, f64)) -> f64 {
+    let (cusp_l, cusp_c) = cusp;
+
+    if (l1 - l0) * cusp_c - (cusp_l - l0) * c1 <= 0.0 {
+        // Lower half, the boundary is a straight line.
+        return cusp_c * l0 / (c1 * cusp_l + cusp_c * (l0 - l1));
+    }
+
+    // Upper half, the boundary is curved: start from the triangle approximation and refine it with
+    // one step of Halley's method.
+    let t = cusp_c * (l0 - 1.0) / (c1 * (cusp_l - 1.0) + cusp_c * (l0 - l1));
+
+    let k_lms = OKLAB_TO_LMS_MATRIX * Vector3{x: 0.0, y: a, z: b};
+    let lms_dt = k_lms.map(|k| (l1 - l0) + c1 * k);
+
+    let l = l0 * (1.0 - t) + t * l1;
+    let c = t * c1;
+    let lms_ = k_lms.map(|k| l + c * k);
+    let lms = lms_.map(|v| v * v * v);
+    let ldt = lms_dt.mul_element_wise(lms_).mul_element_wise(lms_) * 3.0;
+    let ldt2 = lms_dt.mul_element_wise(lms_dt).mul_element_wise(lms_) * 6.0;
+
+    let step = (0..3)
+        .map(|i| {
+            let w = LMS_TO_SRGB_MATRIX.row(i);
+            let (f, f1, f2) = (w.dot(lms) - 1.0, w.dot(ldt), w.dot(ldt2));
+            let u = f1 / (f1 * f1 - 0.5 * f * f2);
+
+            if u >= 0.0 { -f * u } else { f64::MAX }
+        })
+        .fold(f64::MAX, f64::min);
+
+    t + step
+}
+
+/// Converts the cusp `(L, C)` of the *sRGB* gamut to the slopes `(S, T)` of the triangle
+/// approximating it.
+pub(crate) fn to_st(cusp: (f64, f64)) -> (f64, f64) {
+    let (l, c) = cusp;
+
+    (c / l, c / (1.0 - l))
+}
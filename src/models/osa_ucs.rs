@@ -0,0 +1,185 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # OSA-UCS color space
+//!
+//! *Optical Society of America Uniform Color Scales* `(L, j, g)` colourspace, defined for the
+//! *CIE 1964 10 Degree Standard Observer* under *CIE Illuminant D65*. There is no closed form
+//! inverse, the conversion back to *CIE XYZ* is solved iteratively.
+//!
+//! ## References
+//!
+//! [`Cao et al. (2013)` : Inverse conversion from OSA-UCS to CIE XYZ.](https://doi.org/10.1364/JOSAA.30.001508)
+
+use super::Color;
+use crate::models::cie_xyz::Xyz;
+
+use cgmath::*;
+
+const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.7990, y:-0.4493, z:-0.1149},
+    y: Vector3{x: 0.4194, y: 1.3265, z: 0.3394},
+    z: Vector3{x:-0.1648, y: 0.0927, z: 0.7170}
+};
+
+/// Weights of the cube roots of the cone responses for the `j` axis.
+const J: Vector3<f64> = Vector3{x: 1.7, y: 8.0, z: -9.7};
+
+/// Weights of the cube roots of the cone responses for the `g` axis.
+const G: Vector3<f64> = Vector3{x: -13.7, y: 17.7, z: -4.0};
+
+/// Precision of the iterative inverse, relative to the magnitude of the solved value when it is
+/// over 1.
+const EPSILON: f64 = 1e-12;
+
+/// Maximum number of iterations of each step of the iterative inverse.
+const MAX_ITERATIONS: usize = 100;
+
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct OsaUcs {
+    pub l: f64,
+    pub j: f64,
+    pub g: f64,
+}
+
+impl OsaUcs {
+    /// Converts from *CIE XYZ* tristimulus values to *OSA-UCS* colourspace.
+    ///
+    /// Black, whose chromaticity is undefined, converts to `NaN` values.
+    ///
+    /// # Arguments
+    ///
+    /// * `xyz` - *CIE XYZ* tristimulus values, for the *CIE 1964 10 Degree Standard Observer* and
+    ///   relative to *CIE Illuminant D65*.
+    ///
+    /// # Returns
+    ///
+    /// *OSA-UCS* colourspace values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::cie_xyz::Xyz;
+    /// use pigments::models::osa_ucs::OsaUcs;
+    ///
+    /// let ljg = OsaUcs::from_xyz(&Xyz{x: 0.20654008, y: 0.12197225, z: 0.05136952});
+    /// assert!((ljg.l + 3.0049979).abs() < 1e-7);
+    /// assert!((ljg.j - 2.9971370).abs() < 1e-7);
+    /// assert!((ljg.g + 9.6678423).abs() < 1e-7);
+    /// ```
+    pub fn from_xyz(xyz: &Xyz) -> OsaUcs {
+        let xyz = Vector3{x: xyz.x, y: xyz.y, z: xyz.z} * 100.0;
+        let cbrt_y0 = reflectance(xyz).cbrt();
+        let lambda = lambda_of(cbrt_y0);
+        let c = lambda / (5.9 * (cbrt_y0 - 2.0 / 3.0));
+        let rgb = (XYZ_TO_RGB_MATRIX * xyz).map(f64::cbrt);
+
+        OsaUcs{
+            l: (lambda - 14.4) / 2f64.sqrt(),
+            j: c * J.dot(rgb),
+            g: c * G.dot(rgb),
+        }
+    }
+
+    /// Converts from *OSA-UCS* colourspace to *CIE XYZ* tristimulus values.
+    ///
+    /// The cube root of `Y0` is first solved from the lightness. `j` and `g` then determine the
+    /// cube roots of the cone responses up to an achromatic offset, which is solved with *Newton*'s
+    /// method so that the resulting tristimulus values have the same `Y0`. Both solvers stop after
+    /// `MAX_ITERATIONS`, in which case the last estimate is returned; this only happens for values
+    /// far outside of the colours the space was fitted to.
+    ///
+    /// # Returns
+    ///
+    /// *CIE XYZ* tristimulus values, for the *CIE 1964 10 Degree Standard Observer* and relative to
+    /// *CIE Illuminant D65*.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::osa_ucs::OsaUcs;
+    ///
+    /// let xyz = OsaUcs{l: -3.0049979, j: 2.9971370, g: -9.6678423}.to_xyz();
+    /// assert!((xyz.x - 0.20654008).abs() < 1e-8);
+    /// assert!((xyz.y - 0.12197225).abs() < 1e-8);
+    /// assert!((xyz.z - 0.05136952).abs() < 1e-8);
+    ///
+    /// let bright = OsaUcs{l: 1e5, j: 0.0, g: 0.0};
+    /// assert!((OsaUcs::from_xyz(&bright.to_xyz()).l / bright.l - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn to_xyz(&self) -> Xyz {
+        let lambda = self.l * 2f64.sqrt() + 14.4;
+
+        // Λ is strictly increasing with the cube root of Y0, and as the cube root of (Y0 - 30)
+        // lies between the cube root of Y0 minus 5 and the cube root of Y0, the latter is
+        // bracketed by (Λ / 5.9 + 2 / 3 + [0, 0.21]) / 1.042.
+        let target = lambda / 5.9 + 2.0 / 3.0;
+        let (mut min, mut max) = (target / 1.042, (target + 0.21) / 1.042);
+        for _ in 0..MAX_ITERATIONS {
+            if max - min <= EPSILON * max.abs().max(1.0) {
+                break;
+            }
+            let cbrt_y0 = (min + max) / 2.0;
+            if lambda_of(cbrt_y0) < lambda {
+                min = cbrt_y0;
+            } else {
+                max = cbrt_y0;
+            }
+        }
+        let cbrt_y0 = (min + max) / 2.0;
+        let c = lambda / (5.9 * (cbrt_y0 - 2.0 / 3.0));
+
+        // Particular solution orthogonal to the achromatic direction (1, 1, 1), which is the null
+        // space of the j and g weights.
+        let m = Matrix3::from_cols(J, G, Vector3{x: 1.0, y: 1.0, z: 1.0}).transpose();
+        let rgb_p = m.invert().expect("OSA-UCS weights are independent")
+            * Vector3{x: self.j / c, y: self.g / c, z: 0.0};
+
+        let rgb_to_xyz = XYZ_TO_RGB_MATRIX.invert().expect("OSA-UCS matrix is invertible");
+        let xyz_at = |s: f64| rgb_to_xyz * rgb_p.add_element_wise(s).map(|v| v * v * v);
+        let error = |s: f64| reflectance(xyz_at(s)).cbrt() - cbrt_y0;
+
+        let mut s = cbrt_y0;
+        for _ in 0..MAX_ITERATIONS {
+            let scale = s.abs().max(1.0);
+            let h = EPSILON.sqrt() * scale;
+            let e = error(s);
+            let step = e * h / (error(s + h) - e);
+            s -= step;
+            if step.abs() < EPSILON * scale {
+                break;
+            }
+        }
+
+        let xyz = xyz_at(s) / 100.0;
+
+        Xyz{x: xyz.x, y: xyz.y, z: xyz.z}
+    }
+}
+
+impl Color for OsaUcs {}
+
+/// Returns the `Y0` luminous reflectance, corrected for the *Helmholtz–Kohlrausch* effect, of
+/// *CIE XYZ* tristimulus values in the [0, 100] domain.
+fn reflectance(xyz: Vector3<f64>) -> f64 {
+    let sum = xyz.x + xyz.y + xyz.z;
+    let (x, y) = (xyz.x / sum, xyz.y / sum);
+
+    xyz.y * (4.4934 * x * x + 4.3034 * y * y - 4.276 * x * y - 1.3744 * x - 2.5643 * y + 1.8103)
+}
+
+/// Returns Λ from the cube root of `Y0`.
+fn lambda_of(cbrt_y0: f64) -> f64 {
+    5.9 * (cbrt_y0 - 2.0 / 3.0 + 0.042 * (cbrt_y0.powi(3) - 30.0).cbrt())
+}
@@ -0,0 +1,50 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ACES2065-1 (AP0, S-2008-001)
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::ACES;
+pub const WHITEPOINT: Illuminant = Illuminant::ACES;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.7347, y: 0.2653},
+    Vector2{x: 0.0, y: 1.0},
+    Vector2{x: 0.0001, y: -0.077},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.9525523959, y: 0.3439664498, z: 0.0000000000},
+    y: Vector3{x: 0.0000000000, y: 0.7281660966, z: 0.0000000000},
+    z: Vector3{x: 0.0000936786, y:-0.0721325464, z: 1.0088251844}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.0498110175, y:-0.4959030231, z: 0.0000000000},
+    y: Vector3{x: 0.0000000000, y: 1.3733130458, z: 0.0000000000},
+    z: Vector3{x:-0.0000974845, y: 0.0982400361, z: 0.9912520182}
+};
+
+/// *ACES* linear encodings have no transfer function: values are returned unchanged.
+pub fn encode(value: f64) -> f64 {
+    value
+}
+
+/// *ACES* linear encodings have no transfer function: values are returned unchanged.
+pub fn decode(value: f64) -> f64 {
+    value
+}
@@ -0,0 +1,50 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ACEScg (AP1, S-2014-004)
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::ACES;
+pub const WHITEPOINT: Illuminant = Illuminant::ACES;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.713, y: 0.293},
+    Vector2{x: 0.165, y: 0.83},
+    Vector2{x: 0.128, y: 0.044},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.6624541811, y: 0.2722287168, z:-0.0055746495},
+    y: Vector3{x: 0.1340042065, y: 0.6740817658, z: 0.0040607335},
+    z: Vector3{x: 0.1561876870, y: 0.0536895174, z: 1.0103391003}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.6410233797, y:-0.6636628587, z: 0.0117218943},
+    y: Vector3{x:-0.3248032942, y: 1.6153315917, z:-0.0082844420},
+    z: Vector3{x:-0.2364246952, y: 0.0167563477, z: 0.9883948585}
+};
+
+/// *ACES* linear encodings have no transfer function: values are returned unchanged.
+pub fn encode(value: f64) -> f64 {
+    value
+}
+
+/// *ACES* linear encodings have no transfer function: values are returned unchanged.
+pub fn decode(value: f64) -> f64 {
+    value
+}
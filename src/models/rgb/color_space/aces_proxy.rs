@@ -0,0 +1,69 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ACESproxy (AP1, S-2013-001)
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::ACES;
+pub const WHITEPOINT: Illuminant = Illuminant::ACES;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.713, y: 0.293},
+    Vector2{x: 0.165, y: 0.83},
+    Vector2{x: 0.128, y: 0.044},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.6624541811, y: 0.2722287168, z:-0.0055746495},
+    y: Vector3{x: 0.1340042065, y: 0.6740817658, z: 0.0040607335},
+    z: Vector3{x: 0.1561876870, y: 0.0536895174, z: 1.0103391003}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.6410233797, y:-0.6636628587, z: 0.0117218943},
+    y: Vector3{x:-0.3248032942, y: 1.6153315917, z:-0.0082844420},
+    z: Vector3{x:-0.2364246952, y: 0.0167563477, z: 0.9883948585}
+};
+
+const CV_SCALE: f64 = 1023.0;
+const CV_MIN: f64 = 64.0;
+const CV_MAX: f64 = 940.0;
+const STEPS_PER_STOP: f64 = 50.0;
+const MID_CV_OFFSET: f64 = 425.0;
+const MID_LOG_OFFSET: f64 = 2.5;
+
+/// Encodes *ACES* linear *AP1* values with the 10-bit *ACESproxy* encoding (*S-2013-001*).
+///
+/// The result is the integer code value normalised to [0, 1], so it is quantised and clamped to
+/// the [64, 940] legal range.
+pub fn encode(value: f64) -> f64 {
+    let cv = if value > 2.0f64.powf(-9.72) {
+        let cv = (value.log2() + MID_LOG_OFFSET) * STEPS_PER_STOP + MID_CV_OFFSET;
+        cv.round().clamp(CV_MIN, CV_MAX)
+    } else {
+        CV_MIN
+    };
+
+    cv / CV_SCALE
+}
+
+/// Decodes normalised 10-bit *ACESproxy* code values to *ACES* linear *AP1* values (*S-2013-001*).
+pub fn decode(value: f64) -> f64 {
+    let cv = value * CV_SCALE;
+
+    2.0f64.powf((cv - MID_CV_OFFSET) / STEPS_PER_STOP - MID_LOG_OFFSET)
+}
@@ -13,12 +13,19 @@
 // limitations under the License.
 
 use crate::colorimetry::illuminants::Illuminant;
+use crate::transfer;
 
 use cgmath::*;
 
 pub const ILLUMINANT: Illuminant = Illuminant::D65;
 pub const WHITEPOINT: Illuminant = Illuminant::D65;
 
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.64, y: 0.33},
+    Vector2{x: 0.21, y: 0.71},
+    Vector2{x: 0.15, y: 0.06},
+];
+
 pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
     x: Vector3{x:0.57667, y:0.29734, z:0.02703},
     y: Vector3{x:0.18556, y:0.62736, z:0.07069},
@@ -30,3 +37,13 @@ pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
     y: Vector3{x:-0.56501, y: 1.87597, z:-0.11836},
     z: Vector3{x:-0.34473, y: 0.04156, z: 1.01517}
 };
+
+/// Encodes linear values with the *Adobe RGB (1998)* power function of exponent 256/563.
+pub fn encode(value: f64) -> f64 {
+    transfer::gamma::encode(value, 563.0 / 256.0)
+}
+
+/// Decodes *Adobe RGB (1998)* encoded values to linear light with a power function of exponent 563/256.
+pub fn decode(value: f64) -> f64 {
+    transfer::gamma::decode(value, 563.0 / 256.0)
+}
@@ -0,0 +1,51 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Apple RGB
+
+use crate::colorimetry::illuminants::Illuminant;
+use crate::transfer;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.625, y: 0.34},
+    Vector2{x: 0.28, y: 0.595},
+    Vector2{x: 0.155, y: 0.07},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.4496616222, y: 0.2446159225, z: 0.0251810508},
+    y: Vector3{x: 0.3162561183, y: 0.6720442513, z: 0.1411857671},
+    z: Vector3{x: 0.1845381866, y: 0.0833398262, z: 0.9226909328}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 2.9519784797, y:-1.0850835744, z: 0.0854722137},
+    y: Vector3{x:-1.2896043041, y: 1.9908093391, z:-0.2694297118},
+    z: Vector3{x:-0.4739153072, y: 0.0372016778, z: 1.0910276694}
+};
+
+/// Encodes linear values with the *Apple RGB* power function of exponent 1/1.8.
+pub fn encode(value: f64) -> f64 {
+    transfer::gamma::encode(value, 1.8)
+}
+
+/// Decodes *Apple RGB* encoded values to linear light with a power function of exponent 1.8.
+pub fn decode(value: f64) -> f64 {
+    transfer::gamma::decode(value, 1.8)
+}
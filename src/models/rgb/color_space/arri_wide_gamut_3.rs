@@ -0,0 +1,66 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ARRI Wide Gamut 3 with LogC3
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.684, y: 0.313},
+    Vector2{x: 0.221, y: 0.848},
+    Vector2{x: 0.0861, y: -0.102},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.6380076193, y: 0.2919537790, z: 0.0027982790},
+    y: Vector3{x: 0.2147038563, y: 0.8238410415, z:-0.0670342357},
+    z: Vector3{x: 0.0977444514, y:-0.1157948205, z: 1.1532937074}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.7890655510, y:-0.6398486599, z:-0.0415315459},
+    y: Vector3{x:-0.4825338638, y: 1.3963999569, z: 0.0823353736},
+    z: Vector3{x:-0.2000757929, y: 0.1944322918, z: 0.8788684803}
+};
+
+const CUT: f64 = 0.010591;
+const A: f64 = 5.555556;
+const B: f64 = 0.052272;
+const C: f64 = 0.247190;
+const D: f64 = 0.385537;
+const E: f64 = 5.367655;
+const F: f64 = 0.092809;
+
+/// Encodes scene linear values with the *ARRI LogC3* curve, for an exposure index of 800.
+pub fn encode(value: f64) -> f64 {
+    if value > CUT {
+        C * (A * value + B).log10() + D
+    } else {
+        E * value + F
+    }
+}
+
+/// Decodes *ARRI LogC3* values, for an exposure index of 800, to scene linear light.
+pub fn decode(value: f64) -> f64 {
+    if value > E * CUT + F {
+        (10.0f64.powf((value - D) / C) - B) / A
+    } else {
+        (value - F) / E
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ARRI Wide Gamut 4 with LogC4
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.7347, y: 0.2653},
+    Vector2{x: 0.1424, y: 0.8576},
+    Vector2{x: 0.0991, y: -0.0308},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.7048583204, y: 0.2545241764, z: 0.0000000000},
+    y: Vector3{x: 0.1297602952, y: 0.7814777327, z: 0.0000000000},
+    z: Vector3{x: 0.1158373115, y:-0.0360019091, z: 1.0890577508}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.5092154722, y:-0.4915454517, z: 0.0000000000},
+    y: Vector3{x:-0.2505973452, y: 1.3612455459, z: 0.0000000000},
+    z: Vector3{x:-0.1688114753, y: 0.0972829420, z: 0.9182249512}
+};
+
+const A: f64 = (262144.0 - 16.0) / 117.45;
+const B: f64 = (1023.0 - 95.0) / 1023.0;
+const C: f64 = 95.0 / 1023.0;
+
+fn s() -> f64 {
+    (7.0 * 2.0f64.ln() * 2.0f64.powf(7.0 - 14.0 * C / B)) / (A * B)
+}
+
+fn t() -> f64 {
+    (2.0f64.powf(14.0 * (-C / B) + 6.0) - 64.0) / A
+}
+
+/// Encodes scene linear values with the *ARRI LogC4* curve.
+pub fn encode(value: f64) -> f64 {
+    if value >= t() {
+        ((A * value + 64.0).log2() - 6.0) / 14.0 * B + C
+    } else {
+        (value - t()) / s()
+    }
+}
+
+/// Decodes *ARRI LogC4* values to scene linear light.
+pub fn decode(value: f64) -> f64 {
+    if value >= 0.0 {
+        (2.0f64.powf(14.0 * (value - C) / B + 6.0) - 64.0) / A
+    } else {
+        value * s() + t()
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Blackmagic Wide Gamut with Film Generation 5
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.7177215, y: 0.3171181},
+    Vector2{x: 0.228041, y: 0.861569},
+    Vector2{x: 0.1005841, y: -0.0820452},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.6065383683, y: 0.2679929401, z:-0.0294425542},
+    y: Vector3{x: 0.2204127353, y: 0.8327484091, z:-0.0866124303},
+    z: Vector3{x: 0.1235048234, y:-0.1007413492, z: 1.2051127352}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.8663577356, y:-0.6003298545, z: 0.0024514811},
+    y: Vector3{x:-0.5183905088, y: 1.3781199506, z: 0.0863816093},
+    z: Vector3{x:-0.2346067165, y: 0.1767281098, z: 0.8367677153}
+};
+
+const A: f64 = 0.08692876065491224;
+const B: f64 = 0.005494072432257808;
+const C: f64 = 0.5300133392291939;
+const D: f64 = 8.283605932402494;
+const E: f64 = 0.09246575342465753;
+const LIN_CUT: f64 = 0.005;
+
+/// Encodes scene linear values with the *Blackmagic Film Generation 5* curve.
+pub fn encode(value: f64) -> f64 {
+    if value < LIN_CUT {
+        D * value + E
+    } else {
+        A * (value + B).ln() + C
+    }
+}
+
+/// Decodes *Blackmagic Film Generation 5* values to scene linear light.
+pub fn decode(value: f64) -> f64 {
+    if value < D * LIN_CUT + E {
+        (value - E) / D
+    } else {
+        ((value - C) / A).exp() - B
+    }
+}
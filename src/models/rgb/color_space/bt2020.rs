@@ -0,0 +1,57 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ITU-R BT.2020
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.708, y: 0.292},
+    Vector2{x: 0.17, y: 0.797},
+    Vector2{x: 0.131, y: 0.046},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.6369580483, y: 0.2627002120, z: 0.0000000000},
+    y: Vector3{x: 0.1446169036, y: 0.6779980715, z: 0.0280726930},
+    z: Vector3{x: 0.1688809752, y: 0.0593017165, z: 1.0609850577}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.7166511880, y:-0.6666843518, z: 0.0176398574},
+    y: Vector3{x:-0.3556707838, y: 1.6164812366, z:-0.0427706133},
+    z: Vector3{x:-0.2533662814, y: 0.0157685458, z: 0.9421031212}
+};
+
+const ALPHA: f64 = 1.09929682680944;
+const BETA: f64 = 0.018053968510807;
+
+/// Encodes scene linear values with the *ITU-R BT.2020* opto-electronic transfer function.
+pub fn encode(value: f64) -> f64 {
+    let l = value.abs();
+    let v = if l < BETA { 4.5 * l } else { ALPHA * l.powf(0.45) - (ALPHA - 1.0) };
+    value.signum() * v
+}
+
+/// Decodes *ITU-R BT.2020* signal values to scene linear light with the inverse opto-electronic transfer function.
+pub fn decode(value: f64) -> f64 {
+    let v = value.abs();
+    let l = if v < 4.5 * BETA { v / 4.5 } else { ((v + (ALPHA - 1.0)) / ALPHA).powf(1.0 / 0.45) };
+    value.signum() * l
+}
@@ -0,0 +1,53 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ITU-R BT.2100 PQ
+
+use crate::colorimetry::illuminants::Illuminant;
+use crate::transfer;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.708, y: 0.292},
+    Vector2{x: 0.17, y: 0.797},
+    Vector2{x: 0.131, y: 0.046},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.6369580483, y: 0.2627002120, z: 0.0000000000},
+    y: Vector3{x: 0.1446169036, y: 0.6779980715, z: 0.0280726930},
+    z: Vector3{x: 0.1688809752, y: 0.0593017165, z: 1.0609850577}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.7166511880, y:-0.6666843518, z: 0.0176398574},
+    y: Vector3{x:-0.3556707838, y: 1.6164812366, z:-0.0427706133},
+    z: Vector3{x:-0.2533662814, y: 0.0157685458, z: 0.9421031212}
+};
+
+/// Encodes linear values with the *SMPTE ST 2084* inverse *EOTF*, a linear value of 1.0 being the
+/// 10000 cd/m² peak luminance of the signal range.
+pub fn encode(value: f64) -> f64 {
+    transfer::st2084::eotf_inverse(value * transfer::st2084::PEAK_LUMINANCE, transfer::st2084::PEAK_LUMINANCE)
+}
+
+/// Decodes *PQ* signal values to linear values, a linear value of 1.0 being the 10000 cd/m² peak
+/// luminance of the signal range.
+pub fn decode(value: f64) -> f64 {
+    transfer::st2084::eotf(value, transfer::st2084::PEAK_LUMINANCE) / transfer::st2084::PEAK_LUMINANCE
+}
@@ -0,0 +1,51 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ITU-R BT.709
+
+use crate::colorimetry::illuminants::Illuminant;
+use crate::transfer;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.64, y: 0.33},
+    Vector2{x: 0.3, y: 0.6},
+    Vector2{x: 0.15, y: 0.06},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.4123907993, y: 0.2126390059, z: 0.0193308187},
+    y: Vector3{x: 0.3575843394, y: 0.7151686788, z: 0.1191947798},
+    z: Vector3{x: 0.1804807884, y: 0.0721923154, z: 0.9505321522}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 3.2409699419, y:-0.9692436363, z: 0.0556300797},
+    y: Vector3{x:-1.5373831776, y: 1.8759675015, z:-0.2039769589},
+    z: Vector3{x:-0.4986107603, y: 0.0415550574, z: 1.0569715142}
+};
+
+/// Encodes scene linear values with the *ITU-R BT.709* *OETF*.
+pub fn encode(value: f64) -> f64 {
+    transfer::bt709::oetf(value)
+}
+
+/// Decodes *ITU-R BT.709* signal values to scene linear light.
+pub fn decode(value: f64) -> f64 {
+    transfer::bt709::oetf_inverse(value)
+}
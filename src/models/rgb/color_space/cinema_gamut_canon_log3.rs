@@ -0,0 +1,81 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Canon Cinema Gamut with Canon Log 3
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.74, y: 0.27},
+    Vector2{x: 0.17, y: 1.14},
+    Vector2{x: 0.08, y: -0.1},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.7160496466, y: 0.2612613575, z:-0.0096763466},
+    y: Vector3{x: 0.1296834779, y: 0.8696421458, z:-0.2364816361},
+    z: Vector3{x: 0.1047228026, y:-0.1309035033, z: 1.3352157335}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.4898182749, y:-0.4581665745, z:-0.0703496677},
+    y: Vector3{x:-0.2608959022, y: 1.2616277831, z: 0.2215576672},
+    z: Vector3{x:-0.1424265218, y: 0.1596236316, z: 0.7761816036}
+};
+
+/// Encodes scene linear reflectance values with the *Canon Log 3* curve.
+///
+/// Reflectance is scaled so that a 90% white maps to 1.0 before encoding, and the result is a
+/// legal range 10-bit normalised code value.
+pub fn encode(value: f64) -> f64 {
+    let x = value / 0.9;
+    let clog3 = if x < -0.014 {
+        -0.42889912 * (-x * 14.98325 + 1.0).log10() + 0.07623209
+    } else if x <= 0.014 {
+        2.3069815 * x + 0.073059361
+    } else {
+        0.42889912 * (x * 14.98325 + 1.0).log10() + 0.069886632
+    };
+
+    full_to_legal(clog3)
+}
+
+/// Decodes legal range 10-bit normalised *Canon Log 3* code values to scene linear reflectance.
+pub fn decode(value: f64) -> f64 {
+    let clog3 = legal_to_full(value);
+    let x = if clog3 < 0.04076162 {
+        -(10.0f64.powf((0.07623209 - clog3) / 0.42889912) - 1.0) / 14.98325
+    } else if clog3 <= 0.105357102 {
+        (clog3 - 0.073059361) / 2.3069815
+    } else {
+        (10.0f64.powf((clog3 - 0.069886632) / 0.42889912) - 1.0) / 14.98325
+    };
+
+    x * 0.9
+}
+
+/// Converts a full range 10-bit normalised code value to a legal range one.
+fn full_to_legal(value: f64) -> f64 {
+    (value * (940.0 - 64.0) + 64.0) / 1023.0
+}
+
+/// Converts a legal range 10-bit normalised code value to a full range one.
+fn legal_to_full(value: f64) -> f64 {
+    (value * 1023.0 - 64.0) / (940.0 - 64.0)
+}
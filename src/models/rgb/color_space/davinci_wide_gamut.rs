@@ -0,0 +1,65 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # DaVinci Wide Gamut with DaVinci Intermediate
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.8, y: 0.313},
+    Vector2{x: 0.1682, y: 0.9877},
+    Vector2{x: 0.079, y: -0.1155},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.7006223921, y: 0.2741185109, z:-0.0989629129},
+    y: Vector3{x: 0.1487748151, y: 0.8736318959, z:-0.1378953251},
+    z: Vector3{x: 0.1010587198, y:-0.1477504068, z: 1.3259159887}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.5166720420, y:-0.4649171012, z: 0.0648490471},
+    y: Vector3{x:-0.2814780479, y: 1.2514237757, z: 0.1091393437},
+    z: Vector3{x:-0.1469636332, y: 0.1748846089, z: 0.7614146215}
+};
+
+const A: f64 = 0.0075;
+const B: f64 = 7.0;
+const C: f64 = 0.07329248;
+const M: f64 = 10.44426855;
+const LIN_CUT: f64 = 0.00262409;
+const LOG_CUT: f64 = 0.02740668;
+
+/// Encodes scene linear values with the *DaVinci Intermediate* curve.
+pub fn encode(value: f64) -> f64 {
+    if value <= LIN_CUT {
+        value * M
+    } else {
+        ((value + A).log2() + B) * C
+    }
+}
+
+/// Decodes *DaVinci Intermediate* values to scene linear light.
+pub fn decode(value: f64) -> f64 {
+    if value <= LOG_CUT {
+        value / M
+    } else {
+        2.0f64.powf(value / C - B) - A
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # DCI-P3 (SMPTE RP 431-2)
+
+use crate::colorimetry::illuminants::Illuminant;
+use crate::transfer;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::DCIP3;
+pub const WHITEPOINT: Illuminant = Illuminant::DCIP3;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.68, y: 0.32},
+    Vector2{x: 0.265, y: 0.69},
+    Vector2{x: 0.15, y: 0.06},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.4451698156, y: 0.2094916779, z:-0.0000000000},
+    y: Vector3{x: 0.2771344092, y: 0.7215952542, z: 0.0470605601},
+    z: Vector3{x: 0.1722826698, y: 0.0689130679, z: 0.9073553944}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 2.7253940305, y:-0.7951680258, z: 0.0412418914},
+    y: Vector3{x:-1.0180030062, y: 1.6897320548, z:-0.0876390192},
+    z: Vector3{x:-0.4401631952, y: 0.0226471906, z: 1.1009293786}
+};
+
+/// Encodes linear values with the *DCI-P3* power function of exponent 1/2.6.
+pub fn encode(value: f64) -> f64 {
+    transfer::gamma::encode(value, 2.6)
+}
+
+/// Decodes *DCI-P3* encoded values to linear light with a power function of exponent 2.6.
+pub fn decode(value: f64) -> f64 {
+    transfer::gamma::decode(value, 2.6)
+}
@@ -0,0 +1,51 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Display P3
+
+use crate::colorimetry::illuminants::Illuminant;
+use crate::transfer;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.68, y: 0.32},
+    Vector2{x: 0.265, y: 0.69},
+    Vector2{x: 0.15, y: 0.06},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.4865709486, y: 0.2289745641, z:-0.0000000000},
+    y: Vector3{x: 0.2656676932, y: 0.6917385218, z: 0.0451133819},
+    z: Vector3{x: 0.1982172852, y: 0.0792869141, z: 1.0439443689}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 2.4934969119, y:-0.8294889696, z: 0.0358458302},
+    y: Vector3{x:-0.9313836179, y: 1.7626640603, z:-0.0761723893},
+    z: Vector3{x:-0.4027107845, y: 0.0236246858, z: 0.9568845240}
+};
+
+/// Encodes linear values to *Display P3*, which uses the *sRGB* transfer function.
+pub fn encode(value: f64) -> f64 {
+    transfer::srgb::encode(value)
+}
+
+/// Decodes *Display P3* encoded values, which use the *sRGB* transfer function, to linear light.
+pub fn decode(value: f64) -> f64 {
+    transfer::srgb::decode(value)
+}
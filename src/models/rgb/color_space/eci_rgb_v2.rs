@@ -0,0 +1,57 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ECI RGB v2
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D50;
+pub const WHITEPOINT: Illuminant = Illuminant::D50;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.67, y: 0.33},
+    Vector2{x: 0.21, y: 0.71},
+    Vector2{x: 0.14, y: 0.08},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.6503104133, y: 0.3203021439, z:-0.0000000000},
+    y: Vector3{x: 0.1780649461, y: 0.6020291036, z: 0.0678342652},
+    z: Vector3{x: 0.1359203170, y: 0.0776687525, z: 0.7572703373}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.7824707528, y:-0.9594291953, z: 0.0859431187},
+    y: Vector3{x:-0.4969036114, y: 1.9479320025, z:-0.1744905743},
+    z: Vector3{x:-0.2689661750, y:-0.0275826575, z: 1.3230031601}
+};
+
+const EPSILON: f64 = 216.0 / 24389.0;
+const KAPPA: f64 = 24389.0 / 27.0;
+
+/// Encodes linear values with the *CIE L\** lightness curve, scaled to the [0, 1] domain.
+pub fn encode(value: f64) -> f64 {
+    let y = value.abs();
+    let l = if y > EPSILON { 116.0 * y.cbrt() - 16.0 } else { KAPPA * y };
+    value.signum() * l / 100.0
+}
+
+/// Decodes *CIE L\** encoded values, in the [0, 1] domain, to linear light.
+pub fn decode(value: f64) -> f64 {
+    let l = value.abs() * 100.0;
+    let y = if l > KAPPA * EPSILON { ((l + 16.0) / 116.0).powi(3) } else { l / KAPPA };
+    value.signum() * y
+}
@@ -11,56 +11,737 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+mod aces_2065_1;
+mod aces_cc;
+mod aces_cct;
+mod aces_cg;
+mod aces_proxy;
 mod adobe_rgb_1998;
+mod adobe_wide_gamut_rgb;
+mod apple_rgb;
+mod arri_wide_gamut_3;
+mod arri_wide_gamut_4;
+mod blackmagic_wide_gamut;
+mod bt2020;
+mod bt2100_hlg;
+mod bt2100_pq;
+mod bt709;
+mod cinema_gamut_canon_log2;
+mod cinema_gamut_canon_log3;
+mod davinci_wide_gamut;
+mod dci_p3;
+mod dci_p3_d65;
+mod display_p3;
+mod eci_rgb_v2;
+mod prophoto_rgb;
+mod red_wide_gamut_rgb;
+mod s_gamut3;
+mod s_gamut3_cine;
+mod srgb;
+mod v_gamut;
+mod wide_gamut_rgb;
 
-use crate::colorimetry::illuminants::Illuminant;
+use crate::adaptation::vonkries::chromatic_adaptation_matrix;
+use crate::adaptation::AdaptationTransform;
+use crate::colorimetry::illuminants::{Illuminant, StandardObserver};
+use crate::models::illuminant_to_xyz;
 use cgmath::*;
 
 #[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub enum ColorSpace {
     AdobeRgb1998,
+    AdobeWideGamutRgb,
+    AppleRgb,
+    Bt709,
+    Bt2020,
+    /// *ITU-R BT.2100* with the *PQ* transfer function, linear values being normalised so that 1.0 is
+    /// 10000 cd/m².
+    Bt2100Pq,
+    /// *ITU-R BT.2100* with the *HLG* transfer function, linear values being normalised scene light.
+    Bt2100Hlg,
+    DciP3,
+    DciP3D65,
+    DisplayP3,
+    EciRgbV2,
+    ProPhotoRgb,
+    Srgb,
+    WideGamutRgb,
+    /// *ACES2065-1*, the scene-referred interchange and archival encoding on the *AP0* primaries.
+    ///
+    /// The encoding is linear, so values are neither clipped nor quantised.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// // Reference matrices from the Academy technical bulletin TB-2014-004.
+    /// let space = ColorSpace::Aces2065_1;
+    /// let rgb_to_xyz = [
+    ///     [0.9525523959, 0.0000000000, 0.0000936786],
+    ///     [0.3439664498, 0.7281660966, -0.0721325464],
+    ///     [0.0000000000, 0.0000000000, 1.0088251844],
+    /// ];
+    /// let xyz_to_rgb = [
+    ///     [1.0498110175, 0.0000000000, -0.0000974845],
+    ///     [-0.4959030231, 1.3733130458, 0.0982400361],
+    ///     [0.0000000000, 0.0000000000, 0.9912520182],
+    /// ];
+    /// for i in 0..3 {
+    ///     for j in 0..3 {
+    ///         assert!((space.rgb_to_xyz_matrix()[j][i] - rgb_to_xyz[i][j]).abs() < 1e-10);
+    ///         assert!((space.xyz_to_rgb_matrix()[j][i] - xyz_to_rgb[i][j]).abs() < 1e-10);
+    ///     }
+    /// }
+    /// assert_eq!(space.encode(-0.5), -0.5);
+    /// assert_eq!(space.decode(65504.0), 65504.0);
+    /// ```
+    Aces2065_1,
+    /// *ACEScg*, the linear working space on the *AP1* primaries used for rendering and compositing.
+    ///
+    /// The encoding is linear, so values are neither clipped nor quantised.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// // Reference matrices from the Academy technical bulletin TB-2014-004.
+    /// let space = ColorSpace::AcesCg;
+    /// let rgb_to_xyz = [
+    ///     [0.6624541811, 0.1340042065, 0.1561876870],
+    ///     [0.2722287168, 0.6740817658, 0.0536895174],
+    ///     [-0.0055746495, 0.0040607335, 1.0103391003],
+    /// ];
+    /// let xyz_to_rgb = [
+    ///     [1.6410233797, -0.3248032942, -0.2364246952],
+    ///     [-0.6636628587, 1.6153315917, 0.0167563477],
+    ///     [0.0117218943, -0.0082844420, 0.9883948585],
+    /// ];
+    /// for i in 0..3 {
+    ///     for j in 0..3 {
+    ///         assert!((space.rgb_to_xyz_matrix()[j][i] - rgb_to_xyz[i][j]).abs() < 1e-10);
+    ///         assert!((space.xyz_to_rgb_matrix()[j][i] - xyz_to_rgb[i][j]).abs() < 1e-10);
+    ///     }
+    /// }
+    /// assert_eq!(space.encode(-0.5), -0.5);
+    /// assert_eq!(space.decode(65504.0), 65504.0);
+    /// ```
+    AcesCg,
+    /// *ACEScc*, the pure logarithmic grading encoding on the *AP1* primaries.
+    ///
+    /// Non-positive values all encode to the constant `(9.72 - 16) / 17.52`, and decoding saturates
+    /// at 65504, the largest half-float value, so neither end survives a round trip.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// // Reference values from the Academy specification S-2014-003.
+    /// let references = [
+    ///     (0.0, -0.3584475),
+    ///     (2.0f64.powi(-15), -0.3013699),
+    ///     (0.18, 0.4135884),
+    ///     (1.0, 0.5547945),
+    ///     (16.0, 0.7831050),
+    ///     (65504.0, 1.4679963),
+    /// ];
+    /// for (linear, encoded) in references.iter() {
+    ///     assert!((ColorSpace::AcesCc.encode(*linear) - encoded).abs() < 1e-7);
+    /// }
+    /// assert!((ColorSpace::AcesCc.decode(0.4135884) - 0.18).abs() < 1e-7);
+    /// assert_eq!(ColorSpace::AcesCc.encode(-1.0), ColorSpace::AcesCc.encode(0.0));
+    /// assert_eq!(ColorSpace::AcesCc.decode(2.0), 65504.0);
+    /// assert!((ColorSpace::AcesCc.decode(ColorSpace::AcesCc.encode(1e-5)) - 1e-5).abs() < 1e-15);
+    /// ```
+    AcesCc,
+    /// *ACEScct*, the logarithmic grading encoding with a linear toe on the *AP1* primaries.
+    ///
+    /// The linear toe extends below zero, so values are not clipped and negative values survive a
+    /// round trip.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// // Reference values from the Academy specification S-2016-001.
+    /// let references = [
+    ///     (0.0, 0.0729055341958355),
+    ///     (0.0078125, 0.155251141552511),
+    ///     (0.18, 0.4135884),
+    ///     (1.0, 0.5547945),
+    ///     (16.0, 0.7831050),
+    /// ];
+    /// for (linear, encoded) in references.iter() {
+    ///     assert!((ColorSpace::AcesCct.encode(*linear) - encoded).abs() < 1e-7);
+    /// }
+    /// assert!((ColorSpace::AcesCct.decode(0.4135884) - 0.18).abs() < 1e-7);
+    /// assert!((ColorSpace::AcesCct.decode(ColorSpace::AcesCct.encode(-0.01)) + 0.01).abs() < 1e-15);
+    /// assert!((ColorSpace::AcesCct.decode(ColorSpace::AcesCct.encode(0.001)) - 0.001).abs() < 1e-15);
+    /// ```
+    AcesCct,
+    /// *ACESproxy*, the 10-bit integer logarithmic encoding on the *AP1* primaries used for on-set
+    /// transport; values are normalised code values.
+    ///
+    /// Encoding rounds to an integer code value and clamps it to the [64, 940] legal range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// // Reference code values from the Academy specification S-2013-001.
+    /// let references = [(0.0, 64.0), (0.001, 64.0), (0.18, 426.0), (1.0, 550.0), (16.0, 750.0), (1e6, 940.0)];
+    /// for (linear, code) in references.iter() {
+    ///     assert_eq!(ColorSpace::AcesProxy.encode(*linear) * 1023.0, *code);
+    /// }
+    /// assert!((ColorSpace::AcesProxy.decode(550.0 / 1023.0) - 1.0).abs() < 1e-12);
+    ///
+    /// // Every legal code value survives a decode/encode round trip, and values in between are
+    /// // quantised to the nearest code.
+    /// for code in 64..=940 {
+    ///     let value = code as f64 / 1023.0;
+    ///     assert_eq!(ColorSpace::AcesProxy.encode(ColorSpace::AcesProxy.decode(value)), value);
+    /// }
+    /// assert_eq!(ColorSpace::AcesProxy.encode(0.185) * 1023.0, 428.0);
+    /// ```
+    AcesProxy,
+    /// *ARRI Wide Gamut 3* with the *LogC3* curve at an exposure index of 800.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let space = ColorSpace::ArriWideGamut3;
+    /// assert!((space.encode(0.18) - 0.3910068).abs() < 1e-7);
+    /// assert!((space.decode(space.encode(0.18)) - 0.18).abs() < 1e-12);
+    /// ```
+    ArriWideGamut3,
+    /// *ARRI Wide Gamut 4* with the *LogC4* curve.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let space = ColorSpace::ArriWideGamut4;
+    /// assert!((space.encode(0.18) - 0.2783958).abs() < 1e-7);
+    /// assert!((space.decode(space.encode(0.18)) - 0.18).abs() < 1e-12);
+    /// ```
+    ArriWideGamut4,
+    /// *Sony S-Gamut3* with the *S-Log3* curve.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let space = ColorSpace::SGamut3;
+    /// assert!((space.encode(0.18) - 0.4105572).abs() < 1e-7);
+    /// assert!((space.decode(space.encode(0.18)) - 0.18).abs() < 1e-12);
+    /// ```
+    SGamut3,
+    /// *Sony S-Gamut3.Cine* with the *S-Log3* curve.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let space = ColorSpace::SGamut3Cine;
+    /// assert!((space.encode(0.18) - 0.4105572).abs() < 1e-7);
+    /// assert!((space.decode(space.encode(0.18)) - 0.18).abs() < 1e-12);
+    /// ```
+    SGamut3Cine,
+    /// *Panasonic V-Gamut* with the *V-Log* curve.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let space = ColorSpace::VGamut;
+    /// assert!((space.encode(0.18) - 0.4233114).abs() < 1e-7);
+    /// assert!((space.decode(space.encode(0.18)) - 0.18).abs() < 1e-12);
+    /// ```
+    VGamut,
+    /// *Canon Cinema Gamut* with the *Canon Log 2* curve, as legal range 10-bit code values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let space = ColorSpace::CinemaGamutCanonLog2;
+    /// assert!((space.encode(0.18) - 0.3982547).abs() < 1e-7);
+    /// assert!((space.decode(space.encode(0.18)) - 0.18).abs() < 1e-12);
+    /// ```
+    CinemaGamutCanonLog2,
+    /// *Canon Cinema Gamut* with the *Canon Log 3* curve, as legal range 10-bit code values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let space = ColorSpace::CinemaGamutCanonLog3;
+    /// assert!((space.encode(0.18) - 0.3433894).abs() < 1e-7);
+    /// assert!((space.decode(space.encode(0.18)) - 0.18).abs() < 1e-12);
+    /// ```
+    CinemaGamutCanonLog3,
+    /// *RED Wide Gamut RGB* with the *Log3G10* curve.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let space = ColorSpace::RedWideGamutRgb;
+    /// assert!((space.encode(0.18) - 0.3333329).abs() < 1e-7);
+    /// assert!((space.decode(space.encode(0.18)) - 0.18).abs() < 1e-12);
+    /// ```
+    RedWideGamutRgb,
+    /// *Blackmagic Wide Gamut* with the *Blackmagic Film Generation 5* curve.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let space = ColorSpace::BlackmagicWideGamut;
+    /// assert!((space.encode(0.18) - 0.3835616).abs() < 1e-7);
+    /// assert!((space.decode(space.encode(0.18)) - 0.18).abs() < 1e-12);
+    /// ```
+    BlackmagicWideGamut,
+    /// *DaVinci Wide Gamut* with the *DaVinci Intermediate* curve.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// let space = ColorSpace::DaVinciWideGamut;
+    /// assert!((space.encode(0.18) - 0.3360433).abs() < 1e-7);
+    /// assert!((space.decode(space.encode(0.18)) - 0.18).abs() < 1e-12);
+    /// ```
+    DaVinciWideGamut,
 }
 
 impl ColorSpace {
     pub fn illuminant(&self) -> Illuminant {
         match &self {
             ColorSpace::AdobeRgb1998 => self::adobe_rgb_1998::ILLUMINANT,
+            ColorSpace::AdobeWideGamutRgb => self::adobe_wide_gamut_rgb::ILLUMINANT,
+            ColorSpace::AppleRgb => self::apple_rgb::ILLUMINANT,
+            ColorSpace::Bt709 => self::bt709::ILLUMINANT,
+            ColorSpace::Bt2020 => self::bt2020::ILLUMINANT,
+            ColorSpace::Bt2100Pq => self::bt2100_pq::ILLUMINANT,
+            ColorSpace::Bt2100Hlg => self::bt2100_hlg::ILLUMINANT,
+            ColorSpace::DciP3 => self::dci_p3::ILLUMINANT,
+            ColorSpace::DciP3D65 => self::dci_p3_d65::ILLUMINANT,
+            ColorSpace::DisplayP3 => self::display_p3::ILLUMINANT,
+            ColorSpace::EciRgbV2 => self::eci_rgb_v2::ILLUMINANT,
+            ColorSpace::ProPhotoRgb => self::prophoto_rgb::ILLUMINANT,
+            ColorSpace::Srgb => self::srgb::ILLUMINANT,
+            ColorSpace::WideGamutRgb => self::wide_gamut_rgb::ILLUMINANT,
+            ColorSpace::Aces2065_1 => self::aces_2065_1::ILLUMINANT,
+            ColorSpace::AcesCg => self::aces_cg::ILLUMINANT,
+            ColorSpace::AcesCc => self::aces_cc::ILLUMINANT,
+            ColorSpace::AcesCct => self::aces_cct::ILLUMINANT,
+            ColorSpace::AcesProxy => self::aces_proxy::ILLUMINANT,
+            ColorSpace::ArriWideGamut3 => self::arri_wide_gamut_3::ILLUMINANT,
+            ColorSpace::ArriWideGamut4 => self::arri_wide_gamut_4::ILLUMINANT,
+            ColorSpace::SGamut3 => self::s_gamut3::ILLUMINANT,
+            ColorSpace::SGamut3Cine => self::s_gamut3_cine::ILLUMINANT,
+            ColorSpace::VGamut => self::v_gamut::ILLUMINANT,
+            ColorSpace::CinemaGamutCanonLog2 => self::cinema_gamut_canon_log2::ILLUMINANT,
+            ColorSpace::CinemaGamutCanonLog3 => self::cinema_gamut_canon_log3::ILLUMINANT,
+            ColorSpace::RedWideGamutRgb => self::red_wide_gamut_rgb::ILLUMINANT,
+            ColorSpace::BlackmagicWideGamut => self::blackmagic_wide_gamut::ILLUMINANT,
+            ColorSpace::DaVinciWideGamut => self::davinci_wide_gamut::ILLUMINANT,
         }
     }
 
     pub fn whitepoint(&self) -> Illuminant {
         match &self {
-            ColorSpace::AdobeRgb1998 => self::adobe_rgb_1998::ILLUMINANT,
+            ColorSpace::AdobeRgb1998 => self::adobe_rgb_1998::WHITEPOINT,
+            ColorSpace::AdobeWideGamutRgb => self::adobe_wide_gamut_rgb::WHITEPOINT,
+            ColorSpace::AppleRgb => self::apple_rgb::WHITEPOINT,
+            ColorSpace::Bt709 => self::bt709::WHITEPOINT,
+            ColorSpace::Bt2020 => self::bt2020::WHITEPOINT,
+            ColorSpace::Bt2100Pq => self::bt2100_pq::WHITEPOINT,
+            ColorSpace::Bt2100Hlg => self::bt2100_hlg::WHITEPOINT,
+            ColorSpace::DciP3 => self::dci_p3::WHITEPOINT,
+            ColorSpace::DciP3D65 => self::dci_p3_d65::WHITEPOINT,
+            ColorSpace::DisplayP3 => self::display_p3::WHITEPOINT,
+            ColorSpace::EciRgbV2 => self::eci_rgb_v2::WHITEPOINT,
+            ColorSpace::ProPhotoRgb => self::prophoto_rgb::WHITEPOINT,
+            ColorSpace::Srgb => self::srgb::WHITEPOINT,
+            ColorSpace::WideGamutRgb => self::wide_gamut_rgb::WHITEPOINT,
+            ColorSpace::Aces2065_1 => self::aces_2065_1::WHITEPOINT,
+            ColorSpace::AcesCg => self::aces_cg::WHITEPOINT,
+            ColorSpace::AcesCc => self::aces_cc::WHITEPOINT,
+            ColorSpace::AcesCct => self::aces_cct::WHITEPOINT,
+            ColorSpace::AcesProxy => self::aces_proxy::WHITEPOINT,
+            ColorSpace::ArriWideGamut3 => self::arri_wide_gamut_3::WHITEPOINT,
+            ColorSpace::ArriWideGamut4 => self::arri_wide_gamut_4::WHITEPOINT,
+            ColorSpace::SGamut3 => self::s_gamut3::WHITEPOINT,
+            ColorSpace::SGamut3Cine => self::s_gamut3_cine::WHITEPOINT,
+            ColorSpace::VGamut => self::v_gamut::WHITEPOINT,
+            ColorSpace::CinemaGamutCanonLog2 => self::cinema_gamut_canon_log2::WHITEPOINT,
+            ColorSpace::CinemaGamutCanonLog3 => self::cinema_gamut_canon_log3::WHITEPOINT,
+            ColorSpace::RedWideGamutRgb => self::red_wide_gamut_rgb::WHITEPOINT,
+            ColorSpace::BlackmagicWideGamut => self::blackmagic_wide_gamut::WHITEPOINT,
+            ColorSpace::DaVinciWideGamut => self::davinci_wide_gamut::WHITEPOINT,
+        }
+    }
+
+    /// Returns the *CIE xy* chromaticity coordinates of the red, green and blue primaries.
+    pub fn primaries(&self) -> [Vector2<f64>; 3] {
+        match &self {
+            ColorSpace::AdobeRgb1998 => self::adobe_rgb_1998::PRIMARIES,
+            ColorSpace::AdobeWideGamutRgb => self::adobe_wide_gamut_rgb::PRIMARIES,
+            ColorSpace::AppleRgb => self::apple_rgb::PRIMARIES,
+            ColorSpace::Bt709 => self::bt709::PRIMARIES,
+            ColorSpace::Bt2020 => self::bt2020::PRIMARIES,
+            ColorSpace::Bt2100Pq => self::bt2100_pq::PRIMARIES,
+            ColorSpace::Bt2100Hlg => self::bt2100_hlg::PRIMARIES,
+            ColorSpace::DciP3 => self::dci_p3::PRIMARIES,
+            ColorSpace::DciP3D65 => self::dci_p3_d65::PRIMARIES,
+            ColorSpace::DisplayP3 => self::display_p3::PRIMARIES,
+            ColorSpace::EciRgbV2 => self::eci_rgb_v2::PRIMARIES,
+            ColorSpace::ProPhotoRgb => self::prophoto_rgb::PRIMARIES,
+            ColorSpace::Srgb => self::srgb::PRIMARIES,
+            ColorSpace::WideGamutRgb => self::wide_gamut_rgb::PRIMARIES,
+            ColorSpace::Aces2065_1 => self::aces_2065_1::PRIMARIES,
+            ColorSpace::AcesCg => self::aces_cg::PRIMARIES,
+            ColorSpace::AcesCc => self::aces_cc::PRIMARIES,
+            ColorSpace::AcesCct => self::aces_cct::PRIMARIES,
+            ColorSpace::AcesProxy => self::aces_proxy::PRIMARIES,
+            ColorSpace::ArriWideGamut3 => self::arri_wide_gamut_3::PRIMARIES,
+            ColorSpace::ArriWideGamut4 => self::arri_wide_gamut_4::PRIMARIES,
+            ColorSpace::SGamut3 => self::s_gamut3::PRIMARIES,
+            ColorSpace::SGamut3Cine => self::s_gamut3_cine::PRIMARIES,
+            ColorSpace::VGamut => self::v_gamut::PRIMARIES,
+            ColorSpace::CinemaGamutCanonLog2 => self::cinema_gamut_canon_log2::PRIMARIES,
+            ColorSpace::CinemaGamutCanonLog3 => self::cinema_gamut_canon_log3::PRIMARIES,
+            ColorSpace::RedWideGamutRgb => self::red_wide_gamut_rgb::PRIMARIES,
+            ColorSpace::BlackmagicWideGamut => self::blackmagic_wide_gamut::PRIMARIES,
+            ColorSpace::DaVinciWideGamut => self::davinci_wide_gamut::PRIMARIES,
         }
     }
 
     pub fn rgb_to_xyz_matrix(&self) -> Matrix3<f64> {
         match &self {
             ColorSpace::AdobeRgb1998 => self::adobe_rgb_1998::RGB_TO_XYZ_MATRIX,
+            ColorSpace::AdobeWideGamutRgb => self::adobe_wide_gamut_rgb::RGB_TO_XYZ_MATRIX,
+            ColorSpace::AppleRgb => self::apple_rgb::RGB_TO_XYZ_MATRIX,
+            ColorSpace::Bt709 => self::bt709::RGB_TO_XYZ_MATRIX,
+            ColorSpace::Bt2020 => self::bt2020::RGB_TO_XYZ_MATRIX,
+            ColorSpace::Bt2100Pq => self::bt2100_pq::RGB_TO_XYZ_MATRIX,
+            ColorSpace::Bt2100Hlg => self::bt2100_hlg::RGB_TO_XYZ_MATRIX,
+            ColorSpace::DciP3 => self::dci_p3::RGB_TO_XYZ_MATRIX,
+            ColorSpace::DciP3D65 => self::dci_p3_d65::RGB_TO_XYZ_MATRIX,
+            ColorSpace::DisplayP3 => self::display_p3::RGB_TO_XYZ_MATRIX,
+            ColorSpace::EciRgbV2 => self::eci_rgb_v2::RGB_TO_XYZ_MATRIX,
+            ColorSpace::ProPhotoRgb => self::prophoto_rgb::RGB_TO_XYZ_MATRIX,
+            ColorSpace::Srgb => self::srgb::RGB_TO_XYZ_MATRIX,
+            ColorSpace::WideGamutRgb => self::wide_gamut_rgb::RGB_TO_XYZ_MATRIX,
+            ColorSpace::Aces2065_1 => self::aces_2065_1::RGB_TO_XYZ_MATRIX,
+            ColorSpace::AcesCg => self::aces_cg::RGB_TO_XYZ_MATRIX,
+            ColorSpace::AcesCc => self::aces_cc::RGB_TO_XYZ_MATRIX,
+            ColorSpace::AcesCct => self::aces_cct::RGB_TO_XYZ_MATRIX,
+            ColorSpace::AcesProxy => self::aces_proxy::RGB_TO_XYZ_MATRIX,
+            ColorSpace::ArriWideGamut3 => self::arri_wide_gamut_3::RGB_TO_XYZ_MATRIX,
+            ColorSpace::ArriWideGamut4 => self::arri_wide_gamut_4::RGB_TO_XYZ_MATRIX,
+            ColorSpace::SGamut3 => self::s_gamut3::RGB_TO_XYZ_MATRIX,
+            ColorSpace::SGamut3Cine => self::s_gamut3_cine::RGB_TO_XYZ_MATRIX,
+            ColorSpace::VGamut => self::v_gamut::RGB_TO_XYZ_MATRIX,
+            ColorSpace::CinemaGamutCanonLog2 => self::cinema_gamut_canon_log2::RGB_TO_XYZ_MATRIX,
+            ColorSpace::CinemaGamutCanonLog3 => self::cinema_gamut_canon_log3::RGB_TO_XYZ_MATRIX,
+            ColorSpace::RedWideGamutRgb => self::red_wide_gamut_rgb::RGB_TO_XYZ_MATRIX,
+            ColorSpace::BlackmagicWideGamut => self::blackmagic_wide_gamut::RGB_TO_XYZ_MATRIX,
+            ColorSpace::DaVinciWideGamut => self::davinci_wide_gamut::RGB_TO_XYZ_MATRIX,
         }
     }
 
     pub fn xyz_to_rgb_matrix(&self) -> Matrix3<f64> {
         match &self {
             ColorSpace::AdobeRgb1998 => self::adobe_rgb_1998::XYZ_TO_RGB_MATRIX,
+            ColorSpace::AdobeWideGamutRgb => self::adobe_wide_gamut_rgb::XYZ_TO_RGB_MATRIX,
+            ColorSpace::AppleRgb => self::apple_rgb::XYZ_TO_RGB_MATRIX,
+            ColorSpace::Bt709 => self::bt709::XYZ_TO_RGB_MATRIX,
+            ColorSpace::Bt2020 => self::bt2020::XYZ_TO_RGB_MATRIX,
+            ColorSpace::Bt2100Pq => self::bt2100_pq::XYZ_TO_RGB_MATRIX,
+            ColorSpace::Bt2100Hlg => self::bt2100_hlg::XYZ_TO_RGB_MATRIX,
+            ColorSpace::DciP3 => self::dci_p3::XYZ_TO_RGB_MATRIX,
+            ColorSpace::DciP3D65 => self::dci_p3_d65::XYZ_TO_RGB_MATRIX,
+            ColorSpace::DisplayP3 => self::display_p3::XYZ_TO_RGB_MATRIX,
+            ColorSpace::EciRgbV2 => self::eci_rgb_v2::XYZ_TO_RGB_MATRIX,
+            ColorSpace::ProPhotoRgb => self::prophoto_rgb::XYZ_TO_RGB_MATRIX,
+            ColorSpace::Srgb => self::srgb::XYZ_TO_RGB_MATRIX,
+            ColorSpace::WideGamutRgb => self::wide_gamut_rgb::XYZ_TO_RGB_MATRIX,
+            ColorSpace::Aces2065_1 => self::aces_2065_1::XYZ_TO_RGB_MATRIX,
+            ColorSpace::AcesCg => self::aces_cg::XYZ_TO_RGB_MATRIX,
+            ColorSpace::AcesCc => self::aces_cc::XYZ_TO_RGB_MATRIX,
+            ColorSpace::AcesCct => self::aces_cct::XYZ_TO_RGB_MATRIX,
+            ColorSpace::AcesProxy => self::aces_proxy::XYZ_TO_RGB_MATRIX,
+            ColorSpace::ArriWideGamut3 => self::arri_wide_gamut_3::XYZ_TO_RGB_MATRIX,
+            ColorSpace::ArriWideGamut4 => self::arri_wide_gamut_4::XYZ_TO_RGB_MATRIX,
+            ColorSpace::SGamut3 => self::s_gamut3::XYZ_TO_RGB_MATRIX,
+            ColorSpace::SGamut3Cine => self::s_gamut3_cine::XYZ_TO_RGB_MATRIX,
+            ColorSpace::VGamut => self::v_gamut::XYZ_TO_RGB_MATRIX,
+            ColorSpace::CinemaGamutCanonLog2 => self::cinema_gamut_canon_log2::XYZ_TO_RGB_MATRIX,
+            ColorSpace::CinemaGamutCanonLog3 => self::cinema_gamut_canon_log3::XYZ_TO_RGB_MATRIX,
+            ColorSpace::RedWideGamutRgb => self::red_wide_gamut_rgb::XYZ_TO_RGB_MATRIX,
+            ColorSpace::BlackmagicWideGamut => self::blackmagic_wide_gamut::XYZ_TO_RGB_MATRIX,
+            ColorSpace::DaVinciWideGamut => self::davinci_wide_gamut::XYZ_TO_RGB_MATRIX,
+        }
+    }
+
+    /// Encodes a linear light component value with the colour space transfer function.
+    ///
+    /// Power law transfer functions mirror negative values around zero and camera log curves extend
+    /// their linear segment below zero, so that components of out-of-gamut colours survive a
+    /// decode/encode round trip. The other encodings follow their specifications: *PQ* clips
+    /// negative values, *ACEScc* clips non-positive values and *ACESproxy* quantises to clamped
+    /// 10-bit code values, see the documentation of each variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    ///
+    /// assert!((ColorSpace::Srgb.encode(0.18) - 0.4613561295004).abs() < 1e-12);
+    /// assert!((ColorSpace::Srgb.decode(ColorSpace::Srgb.encode(0.18)) - 0.18).abs() < 1e-12);
+    /// ```
+    pub fn encode(&self, value: f64) -> f64 {
+        match &self {
+            ColorSpace::AdobeRgb1998 => self::adobe_rgb_1998::encode(value),
+            ColorSpace::AdobeWideGamutRgb => self::adobe_wide_gamut_rgb::encode(value),
+            ColorSpace::AppleRgb => self::apple_rgb::encode(value),
+            ColorSpace::Bt709 => self::bt709::encode(value),
+            ColorSpace::Bt2020 => self::bt2020::encode(value),
+            ColorSpace::Bt2100Pq => self::bt2100_pq::encode(value),
+            ColorSpace::Bt2100Hlg => self::bt2100_hlg::encode(value),
+            ColorSpace::DciP3 => self::dci_p3::encode(value),
+            ColorSpace::DciP3D65 => self::dci_p3_d65::encode(value),
+            ColorSpace::DisplayP3 => self::display_p3::encode(value),
+            ColorSpace::EciRgbV2 => self::eci_rgb_v2::encode(value),
+            ColorSpace::ProPhotoRgb => self::prophoto_rgb::encode(value),
+            ColorSpace::Srgb => self::srgb::encode(value),
+            ColorSpace::WideGamutRgb => self::wide_gamut_rgb::encode(value),
+            ColorSpace::Aces2065_1 => self::aces_2065_1::encode(value),
+            ColorSpace::AcesCg => self::aces_cg::encode(value),
+            ColorSpace::AcesCc => self::aces_cc::encode(value),
+            ColorSpace::AcesCct => self::aces_cct::encode(value),
+            ColorSpace::AcesProxy => self::aces_proxy::encode(value),
+            ColorSpace::ArriWideGamut3 => self::arri_wide_gamut_3::encode(value),
+            ColorSpace::ArriWideGamut4 => self::arri_wide_gamut_4::encode(value),
+            ColorSpace::SGamut3 => self::s_gamut3::encode(value),
+            ColorSpace::SGamut3Cine => self::s_gamut3_cine::encode(value),
+            ColorSpace::VGamut => self::v_gamut::encode(value),
+            ColorSpace::CinemaGamutCanonLog2 => self::cinema_gamut_canon_log2::encode(value),
+            ColorSpace::CinemaGamutCanonLog3 => self::cinema_gamut_canon_log3::encode(value),
+            ColorSpace::RedWideGamutRgb => self::red_wide_gamut_rgb::encode(value),
+            ColorSpace::BlackmagicWideGamut => self::blackmagic_wide_gamut::encode(value),
+            ColorSpace::DaVinciWideGamut => self::davinci_wide_gamut::encode(value),
+        }
+    }
+
+    /// Decodes a non-linear component value to linear light with the colour space transfer function.
+    ///
+    /// This is the inverse of [`ColorSpace::encode`].
+    pub fn decode(&self, value: f64) -> f64 {
+        match &self {
+            ColorSpace::AdobeRgb1998 => self::adobe_rgb_1998::decode(value),
+            ColorSpace::AdobeWideGamutRgb => self::adobe_wide_gamut_rgb::decode(value),
+            ColorSpace::AppleRgb => self::apple_rgb::decode(value),
+            ColorSpace::Bt709 => self::bt709::decode(value),
+            ColorSpace::Bt2020 => self::bt2020::decode(value),
+            ColorSpace::Bt2100Pq => self::bt2100_pq::decode(value),
+            ColorSpace::Bt2100Hlg => self::bt2100_hlg::decode(value),
+            ColorSpace::DciP3 => self::dci_p3::decode(value),
+            ColorSpace::DciP3D65 => self::dci_p3_d65::decode(value),
+            ColorSpace::DisplayP3 => self::display_p3::decode(value),
+            ColorSpace::EciRgbV2 => self::eci_rgb_v2::decode(value),
+            ColorSpace::ProPhotoRgb => self::prophoto_rgb::decode(value),
+            ColorSpace::Srgb => self::srgb::decode(value),
+            ColorSpace::WideGamutRgb => self::wide_gamut_rgb::decode(value),
+            ColorSpace::Aces2065_1 => self::aces_2065_1::decode(value),
+            ColorSpace::AcesCg => self::aces_cg::decode(value),
+            ColorSpace::AcesCc => self::aces_cc::decode(value),
+            ColorSpace::AcesCct => self::aces_cct::decode(value),
+            ColorSpace::AcesProxy => self::aces_proxy::decode(value),
+            ColorSpace::ArriWideGamut3 => self::arri_wide_gamut_3::decode(value),
+            ColorSpace::ArriWideGamut4 => self::arri_wide_gamut_4::decode(value),
+            ColorSpace::SGamut3 => self::s_gamut3::decode(value),
+            ColorSpace::SGamut3Cine => self::s_gamut3_cine::decode(value),
+            ColorSpace::VGamut => self::v_gamut::decode(value),
+            ColorSpace::CinemaGamutCanonLog2 => self::cinema_gamut_canon_log2::decode(value),
+            ColorSpace::CinemaGamutCanonLog3 => self::cinema_gamut_canon_log3::decode(value),
+            ColorSpace::RedWideGamutRgb => self::red_wide_gamut_rgb::decode(value),
+            ColorSpace::BlackmagicWideGamut => self::blackmagic_wide_gamut::decode(value),
+            ColorSpace::DaVinciWideGamut => self::davinci_wide_gamut::decode(value),
         }
     }
 }
 
 //cached!{
 //    RGB_TO_RGB_MATRIX;
-fn rgb_to_rgb_matrix(input: &ColorSpace, output: &ColorSpace) -> Matrix3<f64> {
+/// Computes the matrix converting linear *RGB* values from the `input` colour space to the `output`
+/// colour space.
+///
+/// When the colour spaces have different whitepoints and an `adaptation` transform is given, a
+/// *Von Kries* chromatic adaptation from the input to the output whitepoint is applied in the
+/// *CIE XYZ* domain.
+///
+/// # Example
+///
+/// ```
+/// use pigments::adaptation::AdaptationTransform;
+/// use pigments::models::rgb::color_space::{rgb_to_rgb_matrix, ColorSpace};
+///
+/// let m = rgb_to_rgb_matrix(&ColorSpace::Srgb, &ColorSpace::ProPhotoRgb, Some(AdaptationTransform::Bradford));
+/// assert!((m.x.x - 0.5293).abs() < 1e-4 && (m.y.x - 0.3301).abs() < 1e-4 && (m.z.x - 0.1406).abs() < 1e-4);
+/// ```
+pub fn rgb_to_rgb_matrix(
+    input: &ColorSpace,
+    output: &ColorSpace,
+    adaptation: Option<AdaptationTransform>,
+) -> Matrix3<f64> {
     let input_matrix = input.rgb_to_xyz_matrix();
     let output_matrix = output.xyz_to_rgb_matrix();
 
-    output_matrix * input_matrix
+    match adaptation {
+        Some(transform) if input.whitepoint() != output.whitepoint() => {
+            let xyz_w = illuminant_to_xyz(StandardObserver::Two, input.whitepoint());
+            let xyz_wr = illuminant_to_xyz(StandardObserver::Two, output.whitepoint());
+            let cat = chromatic_adaptation_matrix(&xyz_w, &xyz_wr, &transform);
+
+            output_matrix * cat * input_matrix
+        },
+        _ => output_matrix * input_matrix,
+    }
 }
 //}
 
+/// *RGB* colour space known at compile time.
+///
+/// It is implemented by zero-sized marker types, one per [`ColorSpace`] variant and with the same
+/// name, so that values from different colour spaces cannot be mixed up, e.g. `Rgb<Srgb>` and
+/// `Rgb<DisplayP3>`.
+pub trait RgbSpace: Copy + Default + Into<ColorSpace> {
+    const COLOR_SPACE: ColorSpace;
+    const ILLUMINANT: Illuminant;
+    const WHITEPOINT: Illuminant;
+    const PRIMARIES: [Vector2<f64>; 3];
+    const RGB_TO_XYZ_MATRIX: Matrix3<f64>;
+    const XYZ_TO_RGB_MATRIX: Matrix3<f64>;
+
+    /// Encodes a linear light component value with the colour space transfer function.
+    fn encode(value: f64) -> f64;
+
+    /// Decodes a non-linear component value to linear light with the colour space transfer function.
+    fn decode(value: f64) -> f64;
+}
+
+macro_rules! rgb_space {
+    ($name:ident, $module:ident) => {
+        #[doc = concat!("Marker type of the [`ColorSpace::", stringify!($name), "`] colour space.")]
+        #[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+        pub struct $name;
+
+        impl RgbSpace for $name {
+            const COLOR_SPACE: ColorSpace = ColorSpace::$name;
+            const ILLUMINANT: Illuminant = self::$module::ILLUMINANT;
+            const WHITEPOINT: Illuminant = self::$module::WHITEPOINT;
+            const PRIMARIES: [Vector2<f64>; 3] = self::$module::PRIMARIES;
+            const RGB_TO_XYZ_MATRIX: Matrix3<f64> = self::$module::RGB_TO_XYZ_MATRIX;
+            const XYZ_TO_RGB_MATRIX: Matrix3<f64> = self::$module::XYZ_TO_RGB_MATRIX;
+
+            fn encode(value: f64) -> f64 {
+                self::$module::encode(value)
+            }
+
+            fn decode(value: f64) -> f64 {
+                self::$module::decode(value)
+            }
+        }
+
+        impl From<$name> for ColorSpace {
+            fn from(_: $name) -> Self {
+                ColorSpace::$name
+            }
+        }
+    };
+}
+
+rgb_space!(AdobeRgb1998, adobe_rgb_1998);
+rgb_space!(AdobeWideGamutRgb, adobe_wide_gamut_rgb);
+rgb_space!(AppleRgb, apple_rgb);
+rgb_space!(Bt709, bt709);
+rgb_space!(Bt2020, bt2020);
+rgb_space!(Bt2100Pq, bt2100_pq);
+rgb_space!(Bt2100Hlg, bt2100_hlg);
+rgb_space!(DciP3, dci_p3);
+rgb_space!(DciP3D65, dci_p3_d65);
+rgb_space!(DisplayP3, display_p3);
+rgb_space!(EciRgbV2, eci_rgb_v2);
+rgb_space!(ProPhotoRgb, prophoto_rgb);
+rgb_space!(Srgb, srgb);
+rgb_space!(WideGamutRgb, wide_gamut_rgb);
+rgb_space!(Aces2065_1, aces_2065_1);
+rgb_space!(AcesCg, aces_cg);
+rgb_space!(AcesCc, aces_cc);
+rgb_space!(AcesCct, aces_cct);
+rgb_space!(AcesProxy, aces_proxy);
+rgb_space!(ArriWideGamut3, arri_wide_gamut_3);
+rgb_space!(ArriWideGamut4, arri_wide_gamut_4);
+rgb_space!(SGamut3, s_gamut3);
+rgb_space!(SGamut3Cine, s_gamut3_cine);
+rgb_space!(VGamut, v_gamut);
+rgb_space!(CinemaGamutCanonLog2, cinema_gamut_canon_log2);
+rgb_space!(CinemaGamutCanonLog3, cinema_gamut_canon_log3);
+rgb_space!(RedWideGamutRgb, red_wide_gamut_rgb);
+rgb_space!(BlackmagicWideGamut, blackmagic_wide_gamut);
+rgb_space!(DaVinciWideGamut, davinci_wide_gamut);
+
 impl std::fmt::Display for ColorSpace {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
             ColorSpace::AdobeRgb1998 => write!(f, "Adobe RGB 1998"),
+            ColorSpace::AdobeWideGamutRgb => write!(f, "Adobe Wide Gamut RGB"),
+            ColorSpace::AppleRgb => write!(f, "Apple RGB"),
+            ColorSpace::Bt709 => write!(f, "ITU-R BT.709"),
+            ColorSpace::Bt2020 => write!(f, "ITU-R BT.2020"),
+            ColorSpace::Bt2100Pq => write!(f, "ITU-R BT.2100 PQ"),
+            ColorSpace::Bt2100Hlg => write!(f, "ITU-R BT.2100 HLG"),
+            ColorSpace::DciP3 => write!(f, "DCI-P3"),
+            ColorSpace::DciP3D65 => write!(f, "DCI-P3-D65"),
+            ColorSpace::DisplayP3 => write!(f, "Display P3"),
+            ColorSpace::EciRgbV2 => write!(f, "ECI RGB v2"),
+            ColorSpace::ProPhotoRgb => write!(f, "ProPhoto RGB"),
+            ColorSpace::Srgb => write!(f, "sRGB"),
+            ColorSpace::WideGamutRgb => write!(f, "Wide Gamut RGB"),
+            ColorSpace::Aces2065_1 => write!(f, "ACES2065-1"),
+            ColorSpace::AcesCg => write!(f, "ACEScg"),
+            ColorSpace::AcesCc => write!(f, "ACEScc"),
+            ColorSpace::AcesCct => write!(f, "ACEScct"),
+            ColorSpace::AcesProxy => write!(f, "ACESproxy"),
+            ColorSpace::ArriWideGamut3 => write!(f, "ARRI Wide Gamut 3 (LogC3)"),
+            ColorSpace::ArriWideGamut4 => write!(f, "ARRI Wide Gamut 4 (LogC4)"),
+            ColorSpace::SGamut3 => write!(f, "S-Gamut3 (S-Log3)"),
+            ColorSpace::SGamut3Cine => write!(f, "S-Gamut3.Cine (S-Log3)"),
+            ColorSpace::VGamut => write!(f, "V-Gamut (V-Log)"),
+            ColorSpace::CinemaGamutCanonLog2 => write!(f, "Cinema Gamut (Canon Log 2)"),
+            ColorSpace::CinemaGamutCanonLog3 => write!(f, "Cinema Gamut (Canon Log 3)"),
+            ColorSpace::RedWideGamutRgb => write!(f, "REDWideGamutRGB (Log3G10)"),
+            ColorSpace::BlackmagicWideGamut => write!(f, "Blackmagic Wide Gamut (Film Gen 5)"),
+            ColorSpace::DaVinciWideGamut => write!(f, "DaVinci Wide Gamut (DaVinci Intermediate)"),
         }
     }
 }
@@ -0,0 +1,51 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ProPhoto RGB (ROMM RGB, ISO 22028-2:2013)
+
+use crate::colorimetry::illuminants::Illuminant;
+use crate::transfer;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D50;
+pub const WHITEPOINT: Illuminant = Illuminant::D50;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.7347, y: 0.2653},
+    Vector2{x: 0.1596, y: 0.8404},
+    Vector2{x: 0.0366, y: 0.0001},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.7977604897, y: 0.2880711282, z: 0.0000000000},
+    y: Vector3{x: 0.1351858372, y: 0.7118432178, z: 0.0000000000},
+    z: Vector3{x: 0.0313493496, y: 0.0000856540, z: 0.8251046025}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.3457989731, y:-0.5446224939, z: 0.0000000000},
+    y: Vector3{x:-0.2555801001, y: 1.5082327413, z:-0.0000000000},
+    z: Vector3{x:-0.0511062851, y: 0.0205360324, z: 1.2119675456}
+};
+
+/// Encodes linear values with the *ROMM RGB* transfer function.
+pub fn encode(value: f64) -> f64 {
+    transfer::romm::encode(value)
+}
+
+/// Decodes *ROMM RGB* encoded values to linear light.
+pub fn decode(value: f64) -> f64 {
+    transfer::romm::decode(value)
+}
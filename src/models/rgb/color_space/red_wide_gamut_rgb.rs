@@ -0,0 +1,64 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # RED Wide Gamut RGB with Log3G10
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.780308, y: 0.304253},
+    Vector2{x: 0.121595, y: 1.493994},
+    Vector2{x: 0.095612, y: -0.084589},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.7352752459, y: 0.2866940995, z:-0.0796808569},
+    y: Vector3{x: 0.0686094106, y: 0.8429791340, z:-0.3473432170},
+    z: Vector3{x: 0.1465712705, y:-0.1296732335, z: 1.5160818246}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.4128064804, y:-0.4862032769, z:-0.0371390109},
+    y: Vector3{x:-0.1775232010, y: 1.2906964268, z: 0.2863759998},
+    z: Vector3{x:-0.1517707320, y: 0.1574006147, z: 0.6876797789}
+};
+
+const A: f64 = 0.224282;
+const B: f64 = 155.975327;
+const C: f64 = 0.01;
+const G: f64 = 15.1927;
+
+/// Encodes scene linear values with the *RED Log3G10* curve (version 3, with a 0.01 offset).
+pub fn encode(value: f64) -> f64 {
+    let x = value + C;
+    if x < 0.0 {
+        x * G
+    } else {
+        A * (x * B + 1.0).log10()
+    }
+}
+
+/// Decodes *RED Log3G10* values to scene linear light.
+pub fn decode(value: f64) -> f64 {
+    if value < 0.0 {
+        value / G - C
+    } else {
+        (10.0f64.powf(value / A) - 1.0) / B - C
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Sony S-Gamut3 with S-Log3
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.73, y: 0.28},
+    Vector2{x: 0.14, y: 0.855},
+    Vector2{x: 0.1, y: -0.05},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.7064827132, y: 0.2709796708, z:-0.0096778454},
+    y: Vector3{x: 0.1288010498, y: 0.7866064112, z: 0.0046000375},
+    z: Vector3{x: 0.1151721641, y:-0.0575860820, z: 1.0941355587}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.5073998991, y:-0.5181517271, z: 0.0155116982},
+    y: Vector3{x:-0.2458221374, y: 1.3553912409, z:-0.0078727714},
+    z: Vector3{x:-0.1716116808, y: 0.1258786682, z: 0.9119163656}
+};
+
+/// Encodes scene linear reflectance values with the *Sony S-Log3* curve.
+pub fn encode(value: f64) -> f64 {
+    if value >= 0.01125 {
+        (420.0 + ((value + 0.01) / (0.18 + 0.01)).log10() * 261.5) / 1023.0
+    } else {
+        (value * (171.2102946929 - 95.0) / 0.01125 + 95.0) / 1023.0
+    }
+}
+
+/// Decodes *Sony S-Log3* values to scene linear reflectance.
+pub fn decode(value: f64) -> f64 {
+    if value >= 171.2102946929 / 1023.0 {
+        10.0f64.powf((value * 1023.0 - 420.0) / 261.5) * (0.18 + 0.01) - 0.01
+    } else {
+        (value * 1023.0 - 95.0) * 0.01125 / (171.2102946929 - 95.0)
+    }
+}
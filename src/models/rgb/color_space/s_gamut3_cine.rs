@@ -0,0 +1,50 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Sony S-Gamut3.Cine with S-Log3
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.766, y: 0.275},
+    Vector2{x: 0.225, y: 0.8},
+    Vector2{x: 0.089, y: -0.087},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.5990839208, y: 0.2150758201, z:-0.0320658495},
+    y: Vector3{x: 0.2489255161, y: 0.8850685017, z:-0.0276583907},
+    z: Vector3{x: 0.1024464902, y:-0.1001443219, z: 1.1487819910}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.8467789693, y:-0.4441532629, z: 0.0408554212},
+    y: Vector3{x:-0.5259861230, y: 1.2594429028, z: 0.0156408893},
+    z: Vector3{x:-0.2105452114, y: 0.1493999729, z: 0.8682072487}
+};
+
+/// Encodes scene linear reflectance values with the *Sony S-Log3* curve.
+pub fn encode(value: f64) -> f64 {
+    super::s_gamut3::encode(value)
+}
+
+/// Decodes *Sony S-Log3* values to scene linear reflectance.
+pub fn decode(value: f64) -> f64 {
+    super::s_gamut3::decode(value)
+}
@@ -0,0 +1,64 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Panasonic V-Gamut with V-Log
+
+use crate::colorimetry::illuminants::Illuminant;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D65;
+pub const WHITEPOINT: Illuminant = Illuminant::D65;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.73, y: 0.28},
+    Vector2{x: 0.165, y: 0.84},
+    Vector2{x: 0.1, y: -0.03},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.6796444699, y: 0.2606855501, z:-0.0093101982},
+    y: Vector3{x: 0.1522114124, y: 0.7748944633, z:-0.0046124670},
+    z: Vector3{x: 0.1186000447, y:-0.0355800134, z: 1.1029804160}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.5890117739, y:-0.5340529104, z: 0.0111794488},
+    y: Vector3{x:-0.3132044845, y: 1.3960114334, z: 0.0031941282},
+    z: Vector3{x:-0.1809648515, y: 0.1024576710, z: 0.9055353563}
+};
+
+const CUT_1: f64 = 0.01;
+const CUT_2: f64 = 0.181;
+const B: f64 = 0.00873;
+const C: f64 = 0.241514;
+const D: f64 = 0.598206;
+
+/// Encodes scene linear reflectance values with the *Panasonic V-Log* curve.
+pub fn encode(value: f64) -> f64 {
+    if value < CUT_1 {
+        5.6 * value + 0.125
+    } else {
+        C * (value + B).log10() + D
+    }
+}
+
+/// Decodes *Panasonic V-Log* values to scene linear reflectance.
+pub fn decode(value: f64) -> f64 {
+    if value < CUT_2 {
+        (value - 0.125) / 5.6
+    } else {
+        10.0f64.powf((value - D) / C) - B
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Wide Gamut RGB
+
+use crate::colorimetry::illuminants::Illuminant;
+use crate::transfer;
+
+use cgmath::*;
+
+pub const ILLUMINANT: Illuminant = Illuminant::D50;
+pub const WHITEPOINT: Illuminant = Illuminant::D50;
+
+pub const PRIMARIES: [Vector2<f64>; 3] = [
+    Vector2{x: 0.7347, y: 0.2653},
+    Vector2{x: 0.1152, y: 0.8264},
+    Vector2{x: 0.1566, y: 0.0177},
+];
+
+pub const RGB_TO_XYZ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.7165007168, y: 0.2587282430, z: 0.0000000000},
+    y: Vector3{x: 0.1010205744, y: 0.7246823149, z: 0.0512118190},
+    z: Vector3{x: 0.1467743853, y: 0.0165894420, z: 0.7738927835}
+};
+
+pub const XYZ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.4623041820, y:-0.5228682777, z: 0.0346004461},
+    y: Vector3{x:-0.1845256396, y: 1.4479884041, z:-0.0958196298},
+    z: Vector3{x:-0.2733810477, y: 0.0681261687, z: 1.2876604565}
+};
+
+/// Encodes linear values with the *Wide Gamut RGB* power function of exponent 1/2.2.
+pub fn encode(value: f64) -> f64 {
+    transfer::gamma::encode(value, 2.2)
+}
+
+/// Decodes *Wide Gamut RGB* encoded values to linear light with a power function of exponent 2.2.
+pub fn decode(value: f64) -> f64 {
+    transfer::gamma::decode(value, 2.2)
+}
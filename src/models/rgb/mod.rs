@@ -13,23 +13,267 @@
 // limitations under the License.
 
 //! # RGB (red, green, blue) color space
+//!
+//! [`Rgb`] holds non-linear values, encoded with the transfer function of their colour space, as
+//! they are usually stored and exchanged. [`LinearRgb`] holds linear light values, which are the
+//! only ones the colour space matrices can be applied to. [`Rgb::linearize`] and
+//! [`LinearRgb::encode`] convert explicitly between the two.
+//!
+//! Both types are generic over their colour space: by default it is a runtime [`ColorSpace`]
+//! value, but it can also be a zero-sized marker type implementing [`RgbSpace`], e.g.
+//! `Rgb<Srgb>`, in which case mixing colour spaces is caught at compile time. [`Rgb::to_static`]
+//! and the `From` implementations convert between the two forms.
 
 pub mod color_space;
 
+use crate::adaptation::{AdaptationTransform, DEFAULT_TRANSFORM};
+use crate::colorimetry::illuminants::Illuminant;
+use crate::models::cie_xyz::Xyz;
+use crate::models::rgb::color_space::{ColorSpace, RgbSpace};
+use cgmath::Vector3;
+
+/// Transfer function encoded *RGB* values.
 #[derive(PartialEq, Clone, Copy, Debug)]
-pub struct Rgb {
+pub struct Rgb<S = ColorSpace> {
     pub r: f64,
     pub g: f64,
     pub b: f64,
 
-    pub space: color_space::ColorSpace,
+    pub space: S,
+}
+
+/// Linear light *RGB* values.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct LinearRgb<S = ColorSpace> {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+
+    pub space: S,
+}
+
+impl<S: Into<ColorSpace> + Copy> Rgb<S> {
+    /// Decodes the values to linear light with the transfer function of the colour space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let rgb = Rgb{r: 0.5, g: 0.5, b: 0.5, space: ColorSpace::Srgb};
+    /// let linear = rgb.linearize();
+    /// assert!((linear.r - 0.2140411).abs() < 1e-7);
+    /// assert_eq!(linear.encode(), rgb);
+    /// ```
+    pub fn linearize(&self) -> LinearRgb<S> {
+        let space: ColorSpace = self.space.into();
+
+        LinearRgb{
+            r: space.decode(self.r),
+            g: space.decode(self.g),
+            b: space.decode(self.b),
+            space: self.space,
+        }
+    }
+
+    /// Converts from a *RGB* colour space to another.
+    ///
+    /// The source transfer function is decoded, the linear values are converted through the
+    /// *CIE XYZ* domain, optionally with a chromatic adaptation when the whitepoints differ, and the
+    /// result is encoded with the target transfer function.
+    ///
+    /// # Arguments
+    ///
+    /// * `space` - target *RGB* colour space.
+    /// * `adaptation` - chromatic adaptation transform applied when the whitepoints differ.
+    ///
+    /// # Returns
+    ///
+    /// *RGB* values in the target colour space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::adaptation::AdaptationTransform;
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let red = Rgb{r: 1.0, g: 0.0, b: 0.0, space: ColorSpace::Srgb};
+    /// let p3 = red.to_rgb(ColorSpace::DisplayP3, None);
+    /// assert!((p3.r - 0.9175).abs() < 1e-4 && (p3.g - 0.2003).abs() < 1e-4 && (p3.b - 0.1386).abs() < 1e-4);
+    ///
+    /// let white = Rgb{r: 1.0, g: 1.0, b: 1.0, space: ColorSpace::Srgb};
+    /// let romm = white.to_rgb(ColorSpace::ProPhotoRgb, Some(AdaptationTransform::Bradford));
+    /// assert!((romm.r - 1.0).abs() < 1e-9 && (romm.g - 1.0).abs() < 1e-9 && (romm.b - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn to_rgb(&self, space: ColorSpace, adaptation: Option<AdaptationTransform>) -> Rgb {
+        self.linearize().to_linear_rgb(space, adaptation).encode()
+    }
+
+    /// Converts from *RGB* values to *CIE XYZ* tristimulus values.
+    ///
+    /// The transfer function is decoded before the colour space matrix is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `adaptation` - chromatic adaptation transform applied from the colour space whitepoint to
+    ///   `target_white` when they differ, [`DEFAULT_TRANSFORM`] when `None`.
+    /// * `target_white` - illuminant the tristimulus values should be relative to.
+    ///
+    /// # Returns
+    ///
+    /// *CIE XYZ* tristimulus values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::adaptation::AdaptationTransform;
+    /// use pigments::colorimetry::illuminants::Illuminant;
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let white = Rgb{r: 1.0, g: 1.0, b: 1.0, space: ColorSpace::Srgb};
+    /// let d65 = white.to_xyz(None, Illuminant::D65);
+    /// assert!((d65.x - 0.9504559).abs() < 1e-7 && (d65.y - 1.0).abs() < 1e-9 && (d65.z - 1.0890578).abs() < 1e-7);
+    ///
+    /// let d50 = white.to_xyz(Some(AdaptationTransform::Bradford), Illuminant::D50);
+    /// assert!((d50.x - 0.9642957).abs() < 1e-7 && (d50.y - 1.0).abs() < 1e-9 && (d50.z - 0.8251046).abs() < 1e-7);
+    /// assert_eq!(white.to_xyz(None, Illuminant::D50), d50);
+    /// ```
+    pub fn to_xyz(&self, adaptation: Option<AdaptationTransform>, target_white: Illuminant) -> Xyz {
+        self.linearize().to_xyz(adaptation, target_white)
+    }
+}
+
+impl<S: RgbSpace> Rgb<S> {
+    /// Creates *RGB* values in a compile-time colour space.
+    pub fn new(r: f64, g: f64, b: f64) -> Self {
+        Rgb{r, g, b, space: S::default()}
+    }
+
+    /// Converts from a compile-time *RGB* colour space to another, optionally with a chromatic
+    /// adaptation when the whitepoints differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::{DisplayP3, Srgb};
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let red = Rgb::<Srgb>::new(1.0, 0.0, 0.0);
+    /// let p3: Rgb<DisplayP3> = red.convert(None);
+    /// assert!((p3.r - 0.9175).abs() < 1e-4 && (p3.g - 0.2003).abs() < 1e-4 && (p3.b - 0.1386).abs() < 1e-4);
+    /// ```
+    pub fn convert<T: RgbSpace>(&self, adaptation: Option<AdaptationTransform>) -> Rgb<T> {
+        let v = self.linearize().to_linear_rgb(T::COLOR_SPACE, adaptation);
+
+        LinearRgb::<T>::new(v.r, v.g, v.b).encode()
+    }
 }
 
 impl Rgb {
-    fn to_rgb(&self, space: color_space::ColorSpace) -> &Rgb {
-        // M = RGB_to_RGB_matrix(input_colorspace, output_colorspace, chromatic_adaptation_transform)
-        // RGB = dot_vector(M, RGB)
-        // return from_range_1(RGB)
-        unimplemented!();
+    /// Converts runtime colour space values to a compile-time colour space, returns `None` when the
+    /// colour spaces differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::{ColorSpace, DisplayP3, Srgb};
+    /// use pigments::models::rgb::Rgb;
+    ///
+    /// let rgb = Rgb{r: 0.2, g: 0.4, b: 0.6, space: ColorSpace::Srgb};
+    /// assert_eq!(rgb.to_static::<Srgb>(), Some(Rgb::<Srgb>::new(0.2, 0.4, 0.6)));
+    /// assert_eq!(rgb.to_static::<DisplayP3>(), None);
+    /// assert_eq!(Rgb::from(Rgb::<Srgb>::new(0.2, 0.4, 0.6)), rgb);
+    /// ```
+    pub fn to_static<T: RgbSpace>(&self) -> Option<Rgb<T>> {
+        if self.space == T::COLOR_SPACE {
+            Some(Rgb{r: self.r, g: self.g, b: self.b, space: T::default()})
+        } else {
+            None
+        }
+    }
+}
+
+impl<S: RgbSpace> From<Rgb<S>> for Rgb {
+    fn from(rgb: Rgb<S>) -> Self {
+        Rgb{r: rgb.r, g: rgb.g, b: rgb.b, space: S::COLOR_SPACE}
+    }
+}
+
+impl<S: Into<ColorSpace> + Copy> LinearRgb<S> {
+    /// Encodes the values with the transfer function of the colour space.
+    pub fn encode(&self) -> Rgb<S> {
+        let space: ColorSpace = self.space.into();
+
+        Rgb{
+            r: space.encode(self.r),
+            g: space.encode(self.g),
+            b: space.encode(self.b),
+            space: self.space,
+        }
+    }
+
+    /// Converts from a linear *RGB* colour space to another, optionally with a chromatic adaptation
+    /// when the whitepoints differ.
+    ///
+    /// # Arguments
+    ///
+    /// * `space` - target *RGB* colour space.
+    /// * `adaptation` - chromatic adaptation transform applied when the whitepoints differ.
+    ///
+    /// # Returns
+    ///
+    /// Linear *RGB* values in the target colour space.
+    pub fn to_linear_rgb(&self, space: ColorSpace, adaptation: Option<AdaptationTransform>) -> LinearRgb {
+        let m = color_space::rgb_to_rgb_matrix(&self.space.into(), &space, adaptation);
+        let v = m * Vector3{x: self.r, y: self.g, z: self.b};
+
+        LinearRgb{r: v.x, g: v.y, b: v.z, space}
+    }
+
+    /// Converts from linear *RGB* values to *CIE XYZ* tristimulus values.
+    ///
+    /// # Arguments
+    ///
+    /// * `adaptation` - chromatic adaptation transform applied from the colour space whitepoint to
+    ///   `target_white` when they differ, [`DEFAULT_TRANSFORM`] when `None`.
+    /// * `target_white` - illuminant the tristimulus values should be relative to.
+    ///
+    /// # Returns
+    ///
+    /// *CIE XYZ* tristimulus values.
+    pub fn to_xyz(&self, adaptation: Option<AdaptationTransform>, target_white: Illuminant) -> Xyz {
+        let space: ColorSpace = self.space.into();
+        let v = space.rgb_to_xyz_matrix() * Vector3{x: self.r, y: self.g, z: self.b};
+        let xyz = Xyz{x: v.x, y: v.y, z: v.z};
+
+        xyz.adapt(space.whitepoint(), target_white, adaptation.unwrap_or(DEFAULT_TRANSFORM))
+    }
+}
+
+impl<S: RgbSpace> LinearRgb<S> {
+    /// Creates linear *RGB* values in a compile-time colour space.
+    pub fn new(r: f64, g: f64, b: f64) -> Self {
+        LinearRgb{r, g, b, space: S::default()}
+    }
+}
+
+impl LinearRgb {
+    /// Converts runtime colour space values to a compile-time colour space, returns `None` when the
+    /// colour spaces differ.
+    pub fn to_static<T: RgbSpace>(&self) -> Option<LinearRgb<T>> {
+        if self.space == T::COLOR_SPACE {
+            Some(LinearRgb{r: self.r, g: self.g, b: self.b, space: T::default()})
+        } else {
+            None
+        }
+    }
+}
+
+impl<S: RgbSpace> From<LinearRgb<S>> for LinearRgb {
+    fn from(rgb: LinearRgb<S>) -> Self {
+        LinearRgb{r: rgb.r, g: rgb.g, b: rgb.b, space: S::COLOR_SPACE}
     }
 }
@@ -0,0 +1,202 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Y'CbCr (luma, blue difference, red difference) color space
+//!
+//! Digital video encoding of transfer function encoded *RGB* values. The luma is in [0, 1] and the
+//! colour differences in [-0.5, 0.5], [`YCbCr::quantize`] converts them to integer code values.
+//!
+//! ## References
+//!
+//! [`ITU-R BT.601-7` : Studio encoding parameters of digital television.](https://www.itu.int/rec/R-REC-BT.601)
+//! [`ITU-R BT.709-6` : Parameter values for the HDTV standards.](https://www.itu.int/rec/R-REC-BT.709)
+//! [`ITU-R BT.2020-2` : Parameter values for ultra-high definition television systems.](https://www.itu.int/rec/R-REC-BT.2020)
+//! [`ITU-R BT.2100-2` : Image parameter values for high dynamic range television.](https://www.itu.int/rec/R-REC-BT.2100)
+//! [`SMPTE 240M-1999` : 1125-Line High-Definition Production Systems.](https://ieeexplore.ieee.org/document/7291461)
+
+use super::Color;
+use crate::models::rgb::color_space::{ColorSpace, RgbSpace};
+use crate::models::rgb::Rgb;
+
+/// Luma coefficients sets.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Coefficients {
+    /// *ITU-R BT.601*, standard definition.
+    Bt601,
+
+    /// *ITU-R BT.709*, high definition.
+    Bt709,
+
+    /// *ITU-R BT.2020*, non-constant luminance.
+    Bt2020,
+
+    /// *SMPTE 240M*.
+    Smpte240M,
+}
+
+impl Coefficients {
+    /// Returns the luma coefficients of the red and blue components, `(Kr, Kb)`.
+    pub fn kr_kb(&self) -> (f64, f64) {
+        match self {
+            Coefficients::Bt601 => (0.299, 0.114),
+            Coefficients::Bt709 => (0.2126, 0.0722),
+            Coefficients::Bt2020 => (0.2627, 0.0593),
+            Coefficients::Smpte240M => (0.212, 0.087),
+        }
+    }
+}
+
+/// Code value ranges.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Range {
+    /// Every code value is used.
+    Full,
+
+    /// Limited, or studio, range: at 8 bits luma spans 16 to 235 and the colour differences 16 to
+    /// 240, leaving headroom and footroom for filter overshoots.
+    Limited,
+}
+
+/// Code value bit depths.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum BitDepth {
+    Eight = 8,
+    Ten = 10,
+    Twelve = 12,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct YCbCr<S = ColorSpace> {
+    pub y: f64,
+    pub cb: f64,
+    pub cr: f64,
+
+    pub space: S,
+}
+
+impl<S: Copy> YCbCr<S> {
+    /// Converts from *RGB* values to *Y'CbCr* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    /// use pigments::models::ycbcr::{Coefficients, YCbCr};
+    ///
+    /// let rgb = Rgb{r: 1.0, g: 0.0, b: 0.0, space: ColorSpace::Bt709};
+    /// let ycbcr = YCbCr::from_rgb(&rgb, Coefficients::Bt709);
+    /// assert!((ycbcr.y - 0.2126).abs() < 1e-9 && (ycbcr.cr - 0.5).abs() < 1e-9);
+    ///
+    /// let rgb_back = ycbcr.to_rgb(Coefficients::Bt709);
+    /// assert!((rgb_back.r - 1.0).abs() < 1e-9 && rgb_back.g.abs() < 1e-9 && rgb_back.b.abs() < 1e-9);
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<S>, coefficients: Coefficients) -> YCbCr<S> {
+        let (kr, kb) = coefficients.kr_kb();
+        let y = kr * rgb.r + (1.0 - kr - kb) * rgb.g + kb * rgb.b;
+
+        YCbCr{
+            y,
+            cb: (rgb.b - y) / (2.0 * (1.0 - kb)),
+            cr: (rgb.r - y) / (2.0 * (1.0 - kr)),
+            space: rgb.space,
+        }
+    }
+
+    /// Converts from *Y'CbCr* values to *RGB* values.
+    pub fn to_rgb(&self, coefficients: Coefficients) -> Rgb<S> {
+        let (kr, kb) = coefficients.kr_kb();
+        let r = self.y + 2.0 * (1.0 - kr) * self.cr;
+        let b = self.y + 2.0 * (1.0 - kb) * self.cb;
+        let g = (self.y - kr * r - kb * b) / (1.0 - kr - kb);
+
+        Rgb{r, g, b, space: self.space}
+    }
+
+    /// Quantises the values to integer code values, `[Y', Cb, Cr]`, clamped to the code range of
+    /// the bit depth.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    /// use pigments::models::ycbcr::{BitDepth, Coefficients, Range, YCbCr};
+    ///
+    /// let rgb = Rgb{r: 1.0, g: 0.0, b: 0.0, space: ColorSpace::Bt709};
+    /// let ycbcr = YCbCr::from_rgb(&rgb, Coefficients::Bt709);
+    /// assert_eq!(ycbcr.quantize(BitDepth::Eight, Range::Limited), [63, 102, 240]);
+    /// assert_eq!(ycbcr.quantize(BitDepth::Ten, Range::Limited), [250, 409, 960]);
+    /// assert_eq!(ycbcr.quantize(BitDepth::Eight, Range::Full), [54, 99, 255]);
+    /// ```
+    pub fn quantize(&self, depth: BitDepth, range: Range) -> [u16; 3] {
+        let (y_scale, y_offset, c_scale, c_offset) = code_range(depth, range);
+        let max = ((1u32 << depth as u32) - 1) as f64;
+        let code = |v: f64| v.round().clamp(0.0, max) as u16;
+
+        [
+            code(y_scale * self.y + y_offset),
+            code(c_scale * self.cb + c_offset),
+            code(c_scale * self.cr + c_offset),
+        ]
+    }
+
+    /// Creates *Y'CbCr* values from integer code values, `[Y', Cb, Cr]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::ycbcr::{BitDepth, Range, YCbCr};
+    ///
+    /// let ycbcr = YCbCr::dequantize([940, 512, 512], BitDepth::Ten, Range::Limited, ColorSpace::Bt2020);
+    /// assert_eq!((ycbcr.y, ycbcr.cb, ycbcr.cr), (1.0, 0.0, 0.0));
+    /// ```
+    pub fn dequantize(codes: [u16; 3], depth: BitDepth, range: Range, space: S) -> YCbCr<S> {
+        let (y_scale, y_offset, c_scale, c_offset) = code_range(depth, range);
+
+        YCbCr{
+            y: (codes[0] as f64 - y_offset) / y_scale,
+            cb: (codes[1] as f64 - c_offset) / c_scale,
+            cr: (codes[2] as f64 - c_offset) / c_scale,
+            space,
+        }
+    }
+}
+
+impl<S: RgbSpace> YCbCr<S> {
+    /// Creates *Y'CbCr* values in a compile-time colour space.
+    pub fn new(y: f64, cb: f64, cr: f64) -> Self {
+        YCbCr{y, cb, cr, space: S::default()}
+    }
+}
+
+impl<S> Color for YCbCr<S> {}
+
+/// Returns the luma scale and offset and the colour differences scale and offset of a code range.
+fn code_range(depth: BitDepth, range: Range) -> (f64, f64, f64, f64) {
+    let n = depth as i32;
+    match range {
+        Range::Full => {
+            let max = 2f64.powi(n) - 1.0;
+
+            (max, 0.0, max, 2f64.powi(n - 1))
+        }
+        Range::Limited => {
+            let scale = 2f64.powi(n - 8);
+
+            (219.0 * scale, 16.0 * scale, 224.0 * scale, 128.0 * scale)
+        }
+    }
+}
@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,3 +13,78 @@
 // limitations under the License.
 
 //! # YIQ (luma, in-phase, quadrature) color space
+//!
+//! Analogue *NTSC* colour television encoding of transfer function encoded *RGB* values, with the
+//! *FCC* coefficients.
+//!
+//! ## References
+//!
+//! [`FCC` : 47 CFR § 73.682 - TV transmission standards.](https://www.law.cornell.edu/cfr/text/47/73.682)
+//! [`Wikipedia` : YIQ.](https://en.wikipedia.org/wiki/YIQ)
+
+use super::Color;
+use crate::models::rgb::color_space::{ColorSpace, RgbSpace};
+use crate::models::rgb::Rgb;
+
+use cgmath::*;
+
+const RGB_TO_YIQ_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 0.30, y: 0.599, z: 0.213},
+    y: Vector3{x: 0.59, y:-0.2773, z:-0.5251},
+    z: Vector3{x: 0.11, y:-0.3217, z: 0.3121}
+};
+
+const YIQ_TO_RGB_MATRIX: Matrix3<f64> = Matrix3 {
+    x: Vector3{x: 1.0, y: 1.0, z: 1.0},
+    y: Vector3{x: 0.9468822170900693, y:-0.27478764629897834, z:-1.1085450346420322},
+    z: Vector3{x: 0.6235565819861432, y:-0.6356910791873801, z: 1.7090069284064666}
+};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Yiq<S = ColorSpace> {
+    pub y: f64,
+    pub i: f64,
+    pub q: f64,
+
+    pub space: S,
+}
+
+impl<S: Copy> Yiq<S> {
+    /// Converts from *RGB* values to *YIQ* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    /// use pigments::models::yiq::Yiq;
+    ///
+    /// let rgb = Rgb{r: 1.0, g: 0.0, b: 0.0, space: ColorSpace::Srgb};
+    /// let yiq = Yiq::from_rgb(&rgb);
+    /// assert_eq!((yiq.y, yiq.i, yiq.q), (0.30, 0.599, 0.213));
+    ///
+    /// let rgb_back = yiq.to_rgb();
+    /// assert!((rgb_back.r - 1.0).abs() < 1e-9 && rgb_back.g.abs() < 1e-9 && rgb_back.b.abs() < 1e-9);
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<S>) -> Yiq<S> {
+        let v = RGB_TO_YIQ_MATRIX * Vector3{x: rgb.r, y: rgb.g, z: rgb.b};
+
+        Yiq{y: v.x, i: v.y, q: v.z, space: rgb.space}
+    }
+
+    /// Converts from *YIQ* values to *RGB* values.
+    pub fn to_rgb(&self) -> Rgb<S> {
+        let v = YIQ_TO_RGB_MATRIX * Vector3{x: self.y, y: self.i, z: self.q};
+
+        Rgb{r: v.x, g: v.y, b: v.z, space: self.space}
+    }
+}
+
+impl<S: RgbSpace> Yiq<S> {
+    /// Creates *YIQ* values in a compile-time colour space.
+    pub fn new(y: f64, i: f64, q: f64) -> Self {
+        Yiq{y, i, q, space: S::default()}
+    }
+}
+
+impl<S> Color for Yiq<S> {}
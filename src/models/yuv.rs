@@ -1,4 +1,4 @@
-// Copyright © 2020 Xavier Basty <xavier@hexbee.net>
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 // you may not use this file except in compliance with the License.
@@ -13,3 +13,73 @@
 // limitations under the License.
 
 //! # YUV (luma, blue projection, red projection) color space
+//!
+//! Analogue *PAL* colour television encoding of transfer function encoded *RGB* values, with the
+//! *BT.601* luma coefficients. Digital video uses [`YCbCr`](crate::models::ycbcr::YCbCr) instead.
+//!
+//! ## References
+//!
+//! [`ITU-R BT.470-6` : Conventional television systems.](https://www.itu.int/rec/R-REC-BT.470)
+
+use super::Color;
+use crate::models::rgb::color_space::{ColorSpace, RgbSpace};
+use crate::models::rgb::Rgb;
+
+/// Luma coefficients of the red and blue components.
+const KR: f64 = 0.299;
+const KB: f64 = 0.114;
+
+/// Scale factors of the colour difference signals.
+const U_SCALE: f64 = 0.492;
+const V_SCALE: f64 = 0.877;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Yuv<S = ColorSpace> {
+    pub y: f64,
+    pub u: f64,
+    pub v: f64,
+
+    pub space: S,
+}
+
+impl<S: Copy> Yuv<S> {
+    /// Converts from *RGB* values to *YUV* values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pigments::models::rgb::color_space::ColorSpace;
+    /// use pigments::models::rgb::Rgb;
+    /// use pigments::models::yuv::Yuv;
+    ///
+    /// let rgb = Rgb{r: 0.0, g: 0.0, b: 1.0, space: ColorSpace::Srgb};
+    /// let yuv = Yuv::from_rgb(&rgb);
+    /// assert!((yuv.y - 0.114).abs() < 1e-9 && (yuv.u - 0.436).abs() < 1e-3 && (yuv.v + 0.1).abs() < 1e-3);
+    ///
+    /// let rgb_back = yuv.to_rgb();
+    /// assert!(rgb_back.r.abs() < 1e-9 && rgb_back.g.abs() < 1e-9 && (rgb_back.b - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn from_rgb(rgb: &Rgb<S>) -> Yuv<S> {
+        let y = KR * rgb.r + (1.0 - KR - KB) * rgb.g + KB * rgb.b;
+
+        Yuv{y, u: U_SCALE * (rgb.b - y), v: V_SCALE * (rgb.r - y), space: rgb.space}
+    }
+
+    /// Converts from *YUV* values to *RGB* values.
+    pub fn to_rgb(&self) -> Rgb<S> {
+        let r = self.y + self.v / V_SCALE;
+        let b = self.y + self.u / U_SCALE;
+        let g = (self.y - KR * r - KB * b) / (1.0 - KR - KB);
+
+        Rgb{r, g, b, space: self.space}
+    }
+}
+
+impl<S: RgbSpace> Yuv<S> {
+    /// Creates *YUV* values in a compile-time colour space.
+    pub fn new(y: f64, u: f64, v: f64) -> Self {
+        Yuv{y, u, v, space: S::default()}
+    }
+}
+
+impl<S> Color for Yuv<S> {}
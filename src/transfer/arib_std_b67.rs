@@ -0,0 +1,172 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ARIB STD-B67 hybrid log-gamma
+//!
+//! Transfer functions of the *HLG* high dynamic range system, as normalised in *ITU-R BT.2100*:
+//! scene linear light is in [0, 1] rather than the [0, 12] range of the original *ARIB STD-B67*.
+//!
+//! ## References
+//!
+//! [`ARIB STD-B67` : Essential Parameter Values for the Extended Image Dynamic Range Television (EIDRTV) System for Programme Production.](https://www.arib.or.jp/english/std_tr/broadcasting/desc/std-b67.html)
+//! [`ITU-R BT.2100-2` : Image parameter values for high dynamic range television for use in production and international programme exchange.](https://www.itu.int/rec/R-REC-BT.2100)
+
+use cgmath::*;
+
+const A: f64 = 0.17883277;
+const B: f64 = 1.0 - 4.0 * A;
+const C: f64 = 0.559910729529562;
+
+/// Luminance coefficients of the *ITU-R BT.2020* primaries, used to compute the scene and display
+/// luminances of the *OOTF*.
+const LUMINANCE: Vector3<f64> = Vector3{x: 0.2627, y: 0.6780, z: 0.0593};
+
+/// Returns the *HLG* system gamma for a display of nominal peak luminance `l_w`, in cd/m².
+///
+/// # Example
+///
+/// ```
+/// use pigments::transfer::arib_std_b67;
+///
+/// assert!((arib_std_b67::system_gamma(1000.0) - 1.2).abs() < 1e-15);
+/// ```
+pub fn system_gamma(l_w: f64) -> f64 {
+    1.2 + 0.42 * (l_w / 1000.0).log10()
+}
+
+/// Encodes normalised scene linear light values with the *HLG* *OETF*.
+///
+/// # Example
+///
+/// ```
+/// use pigments::transfer::arib_std_b67;
+///
+/// assert!((arib_std_b67::oetf(1.0 / 12.0) - 0.5).abs() < 1e-15);
+/// assert!((arib_std_b67::oetf(1.0) - 1.0).abs() < 1e-8);
+/// assert!((arib_std_b67::oetf_inverse(arib_std_b67::oetf(0.18)) - 0.18).abs() < 1e-15);
+/// ```
+pub fn oetf(value: f64) -> f64 {
+    let e = value.abs();
+    let v = if e <= 1.0 / 12.0 { (3.0 * e).sqrt() } else { A * (12.0 * e - B).ln() + C };
+    value.signum() * v
+}
+
+/// Decodes *HLG* signal values to normalised scene linear light, this is the inverse of [`oetf`].
+pub fn oetf_inverse(value: f64) -> f64 {
+    let v = value.abs();
+    let e = if v <= 0.5 { v * v / 3.0 } else { (((v - C) / A).exp() + B) / 12.0 };
+    value.signum() * e
+}
+
+/// Converts normalised scene linear light to display linear light with the *HLG* *OOTF*.
+///
+/// # Arguments
+///
+/// * `rgb` - normalised scene linear *RGB* values.
+/// * `l_w` - nominal peak luminance of the display, in cd/m².
+/// * `gamma` - system gamma, usually given by [`system_gamma`].
+///
+/// # Returns
+///
+/// Display linear *RGB* values, in cd/m².
+pub fn ootf(rgb: Vector3<f64>, l_w: f64, gamma: f64) -> Vector3<f64> {
+    let y_s = LUMINANCE.dot(rgb);
+    if y_s <= 0.0 {
+        return Vector3::zero();
+    }
+
+    rgb * (l_w * y_s.powf(gamma - 1.0))
+}
+
+/// Converts display linear light to normalised scene linear light, this is the inverse of
+/// [`ootf`].
+///
+/// # Arguments
+///
+/// * `rgb` - display linear *RGB* values, in cd/m².
+/// * `l_w` - nominal peak luminance of the display, in cd/m².
+/// * `gamma` - system gamma, usually given by [`system_gamma`].
+///
+/// # Returns
+///
+/// Normalised scene linear *RGB* values.
+pub fn ootf_inverse(rgb: Vector3<f64>, l_w: f64, gamma: f64) -> Vector3<f64> {
+    let y_d = LUMINANCE.dot(rgb);
+    if y_d <= 0.0 {
+        return Vector3::zero();
+    }
+
+    let y_s = (y_d / l_w).powf(1.0 / gamma);
+
+    rgb / (l_w * y_s.powf(gamma - 1.0))
+}
+
+/// Returns the black level lift of the *EOTF* for the given display luminances.
+fn black_level_lift(l_b: f64, l_w: f64) -> f64 {
+    (3.0 * (l_b / l_w).powf(1.0 / system_gamma(l_w))).sqrt()
+}
+
+/// Converts *HLG* signal values to display linear light with the *HLG* *EOTF*, which is the
+/// inverse *OETF* followed by the *OOTF* at the display system gamma.
+///
+/// # Arguments
+///
+/// * `rgb` - non-linear *HLG* *RGB* signal values.
+/// * `l_b` - display luminance for black, in cd/m².
+/// * `l_w` - nominal peak luminance of the display, in cd/m².
+///
+/// # Returns
+///
+/// Display linear *RGB* values, in cd/m².
+///
+/// # Example
+///
+/// ```
+/// use cgmath::{InnerSpace, Vector3};
+/// use pigments::transfer::arib_std_b67;
+///
+/// // The 75% HLG reference white is displayed at 203 cd/m² on a 1000 cd/m² display.
+/// let white = arib_std_b67::eotf(Vector3{x: 0.75, y: 0.75, z: 0.75}, 0.0, 1000.0);
+/// assert!((white.y - 203.0).abs() < 0.5);
+///
+/// let signal = Vector3{x: 0.2, y: 0.5, z: 0.9};
+/// let display = arib_std_b67::eotf(signal, 0.005, 1000.0);
+/// let back = arib_std_b67::eotf_inverse(display, 0.005, 1000.0);
+/// assert!((back - signal).magnitude() < 1e-12);
+/// ```
+pub fn eotf(rgb: Vector3<f64>, l_b: f64, l_w: f64) -> Vector3<f64> {
+    let beta = black_level_lift(l_b, l_w);
+    let lift = |v: f64| oetf_inverse(((1.0 - beta) * v + beta).max(0.0));
+
+    ootf(Vector3{x: lift(rgb.x), y: lift(rgb.y), z: lift(rgb.z)}, l_w, system_gamma(l_w))
+}
+
+/// Converts display linear light to *HLG* signal values, this is the inverse of [`eotf`].
+///
+/// # Arguments
+///
+/// * `rgb` - display linear *RGB* values, in cd/m².
+/// * `l_b` - display luminance for black, in cd/m².
+/// * `l_w` - nominal peak luminance of the display, in cd/m².
+///
+/// # Returns
+///
+/// Non-linear *HLG* *RGB* signal values.
+pub fn eotf_inverse(rgb: Vector3<f64>, l_b: f64, l_w: f64) -> Vector3<f64> {
+    let beta = black_level_lift(l_b, l_w);
+    let scene = ootf_inverse(rgb, l_w, system_gamma(l_w));
+    let unlift = |v: f64| (oetf(v) - beta) / (1.0 - beta);
+
+    Vector3{x: unlift(scene.x), y: unlift(scene.y), z: unlift(scene.z)}
+}
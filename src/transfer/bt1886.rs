@@ -0,0 +1,75 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ITU-R BT.1886 electro-optical transfer function
+//!
+//! Reference *EOTF* of flat panel displays used in HDTV studio production.
+//!
+//! ## References
+//!
+//! [`ITU-R BT.1886` : Reference electro-optical transfer function for flat panel displays used in HDTV studio production.](https://www.itu.int/rec/R-REC-BT.1886)
+
+const GAMMA: f64 = 2.4;
+
+/// Returns the `a` (user gain) and `b` (black level lift) parameters of the *EOTF* for the given
+/// screen luminances.
+fn parameters(l_b: f64, l_w: f64) -> (f64, f64) {
+    let n = l_w.powf(1.0 / GAMMA) - l_b.powf(1.0 / GAMMA);
+
+    (n.powf(GAMMA), l_b.powf(1.0 / GAMMA) / n)
+}
+
+/// Converts a normalised video signal value to screen luminance with the *ITU-R BT.1886* *EOTF*.
+///
+/// # Arguments
+///
+/// * `value` - normalised input video signal level.
+/// * `l_b` - screen luminance for black, in cd/m².
+/// * `l_w` - screen luminance for white, in cd/m².
+///
+/// # Returns
+///
+/// Screen luminance, in cd/m².
+///
+/// # Example
+///
+/// ```
+/// use pigments::transfer::bt1886;
+///
+/// assert!((bt1886::eotf(0.5, 0.0, 100.0) - 18.9464571).abs() < 1e-7);
+/// assert!((bt1886::eotf(0.0, 0.1, 100.0) - 0.1).abs() < 1e-12);
+/// assert!((bt1886::eotf_inverse(bt1886::eotf(0.5, 0.1, 100.0), 0.1, 100.0) - 0.5).abs() < 1e-12);
+/// ```
+pub fn eotf(value: f64, l_b: f64, l_w: f64) -> f64 {
+    let (a, b) = parameters(l_b, l_w);
+
+    a * (value + b).max(0.0).powf(GAMMA)
+}
+
+/// Converts screen luminance to a normalised video signal value, this is the inverse of [`eotf`].
+///
+/// # Arguments
+///
+/// * `value` - screen luminance, in cd/m².
+/// * `l_b` - screen luminance for black, in cd/m².
+/// * `l_w` - screen luminance for white, in cd/m².
+///
+/// # Returns
+///
+/// Normalised video signal level.
+pub fn eotf_inverse(value: f64, l_b: f64, l_w: f64) -> f64 {
+    let (a, b) = parameters(l_b, l_w);
+
+    (value / a).max(0.0).powf(1.0 / GAMMA) - b
+}
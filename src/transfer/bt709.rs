@@ -0,0 +1,46 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ITU-R BT.709 opto-electronic transfer function
+//!
+//! ## References
+//!
+//! [`ITU-R BT.709-6` : Parameter values for the HDTV standards for production and international programme exchange.](https://www.itu.int/rec/R-REC-BT.709)
+
+const ALPHA: f64 = 1.099;
+const BETA: f64 = 0.018;
+
+/// Encodes scene linear light values with the *ITU-R BT.709* *OETF*.
+///
+/// # Example
+///
+/// ```
+/// use pigments::transfer::bt709;
+///
+/// assert!((bt709::oetf(0.18) - 0.4090077).abs() < 1e-7);
+/// assert!((bt709::oetf_inverse(bt709::oetf(0.18)) - 0.18).abs() < 1e-15);
+/// assert!((bt709::oetf_inverse(bt709::oetf(0.01)) - 0.01).abs() < 1e-15);
+/// ```
+pub fn oetf(value: f64) -> f64 {
+    let l = value.abs();
+    let v = if l < BETA { 4.5 * l } else { ALPHA * l.powf(0.45) - (ALPHA - 1.0) };
+    value.signum() * v
+}
+
+/// Decodes *ITU-R BT.709* signal values to scene linear light, this is the inverse of [`oetf`].
+pub fn oetf_inverse(value: f64) -> f64 {
+    let v = value.abs();
+    let l = if v < 4.5 * BETA { v / 4.5 } else { ((v + (ALPHA - 1.0)) / ALPHA).powf(1.0 / 0.45) };
+    value.signum() * l
+}
@@ -0,0 +1,90 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # DICOM grayscale standard display function
+//!
+//! *EOTF* mapping *JND* (just-noticeable difference) indices to luminance, used to calibrate
+//! medical displays.
+//!
+//! ## References
+//!
+//! [`NEMA PS3.14` : Digital Imaging and Communications in Medicine (DICOM) Part 14: Grayscale Standard Display Function.](https://dicom.nema.org/medical/dicom/current/output/html/part14.html)
+
+const A: f64 = -1.3011877;
+const B: f64 = -2.5840191e-2;
+const C: f64 = 8.0242636e-2;
+const D: f64 = -1.0320229e-1;
+const E: f64 = 1.3646699e-1;
+const F: f64 = 2.8745620e-2;
+const G: f64 = -2.5468404e-2;
+const H: f64 = -3.1978977e-3;
+const K: f64 = 1.2992634e-4;
+const M: f64 = 1.3635334e-3;
+
+const INVERSE: [f64; 9] = [
+    71.498068,
+    94.593053,
+    41.912053,
+    9.8247004,
+    0.28175407,
+    -1.1878455,
+    -0.18014349,
+    0.14710899,
+    -0.017046845,
+];
+
+/// Converts a *JND* index to luminance with the *DICOM* *GSDF*.
+///
+/// # Arguments
+///
+/// * `j` - *JND* index, in [1, 1023].
+///
+/// # Returns
+///
+/// Luminance, in cd/m².
+///
+/// # Example
+///
+/// ```
+/// use pigments::transfer::dicom_gsdf;
+///
+/// assert!((dicom_gsdf::eotf(1.0) - 0.05).abs() < 1e-4);
+/// assert!((dicom_gsdf::eotf(1023.0) - 3993.4).abs() < 0.1);
+/// assert!((dicom_gsdf::eotf_inverse(dicom_gsdf::eotf(512.0)) - 512.0).abs() < 0.1);
+/// ```
+pub fn eotf(j: f64) -> f64 {
+    let x = j.ln();
+    let n = A + C * x + E * x.powi(2) + G * x.powi(3) + M * x.powi(4);
+    let d = 1.0 + B * x + D * x.powi(2) + F * x.powi(3) + H * x.powi(4) + K * x.powi(5);
+
+    10.0f64.powf(n / d)
+}
+
+/// Converts luminance to a *JND* index, this is the inverse of [`eotf`].
+///
+/// The standard defines the inverse as a separate polynomial fit, it matches [`eotf`] within a
+/// tenth of a *JND* over the [0.05, 4000] cd/m² range.
+///
+/// # Arguments
+///
+/// * `l` - luminance, in cd/m².
+///
+/// # Returns
+///
+/// *JND* index.
+pub fn eotf_inverse(l: f64) -> f64 {
+    let x = l.log10();
+
+    INVERSE.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
@@ -0,0 +1,35 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Pure power transfer function
+
+/// Encodes linear light values with a pure power function of exponent `1 / gamma`.
+///
+/// # Example
+///
+/// ```
+/// use pigments::transfer::gamma;
+///
+/// assert!((gamma::encode(0.18, 2.2) - 0.4586564).abs() < 1e-7);
+/// assert!((gamma::decode(gamma::encode(0.18, 2.2), 2.2) - 0.18).abs() < 1e-15);
+/// ```
+pub fn encode(value: f64, gamma: f64) -> f64 {
+    value.signum() * value.abs().powf(1.0 / gamma)
+}
+
+/// Decodes values to linear light with a pure power function of exponent `gamma`, this is the
+/// inverse of [`encode`].
+pub fn decode(value: f64, gamma: f64) -> f64 {
+    value.signum() * value.abs().powf(gamma)
+}
@@ -0,0 +1,32 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Transfer functions
+//!
+//! Colour component transfer functions converting between linear light and non-linear signal
+//! values: opto-electronic (*OETF*), electro-optical (*EOTF*) and opto-optical (*OOTF*) transfer
+//! functions, along with their inverses.
+//!
+//! The *sRGB*, *ROMM RGB*, power law, *ITU-R BT.709* and *HLG* functions are only defined for
+//! non-negative values; they mirror negative values around zero so that components of
+//! out-of-gamut colours survive an encode/decode round trip.
+
+pub mod arib_std_b67;
+pub mod bt1886;
+pub mod bt709;
+pub mod dicom_gsdf;
+pub mod gamma;
+pub mod romm;
+pub mod srgb;
+pub mod st2084;
@@ -0,0 +1,47 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ROMM RGB transfer function
+//!
+//! Transfer function used by *ProPhoto RGB*.
+//!
+//! ## References
+//!
+//! [`ISO 22028-2:2013` : Photography and graphic technology - Extended colour encodings for digital image storage, manipulation and interchange - Part 2: Reference output medium metric RGB colour image encoding (ROMM RGB).](https://www.iso.org/standard/56591.html)
+
+const E_T: f64 = 1.0 / 512.0;
+
+/// Encodes linear light values with the *ROMM RGB* transfer function.
+///
+/// # Example
+///
+/// ```
+/// use pigments::transfer::romm;
+///
+/// assert!((romm::encode(0.18) - 0.3857114).abs() < 1e-7);
+/// assert!((romm::decode(romm::encode(0.18)) - 0.18).abs() < 1e-15);
+/// assert!((romm::decode(romm::encode(0.001)) - 0.001).abs() < 1e-15);
+/// ```
+pub fn encode(value: f64) -> f64 {
+    let v = value.abs();
+    let e = if v < E_T { 16.0 * v } else { v.powf(1.0 / 1.8) };
+    value.signum() * e
+}
+
+/// Decodes *ROMM RGB* encoded values to linear light, this is the inverse of [`encode`].
+pub fn decode(value: f64) -> f64 {
+    let v = value.abs();
+    let l = if v < 16.0 * E_T { v / 16.0 } else { v.powf(1.8) };
+    value.signum() * l
+}
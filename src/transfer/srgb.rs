@@ -0,0 +1,43 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # sRGB transfer function
+//!
+//! ## References
+//!
+//! [`IEC 61966-2-1:1999` : Multimedia systems and equipment - Colour measurement and management - Part 2-1: Colour management - Default RGB colour space - sRGB.](https://webstore.iec.ch/publication/6169)
+
+/// Encodes linear light values with the *sRGB* piecewise transfer function.
+///
+/// # Example
+///
+/// ```
+/// use pigments::transfer::srgb;
+///
+/// assert!((srgb::encode(0.18) - 0.4613561295004).abs() < 1e-12);
+/// assert!((srgb::decode(srgb::encode(0.18)) - 0.18).abs() < 1e-15);
+/// assert!((srgb::decode(srgb::encode(-0.002)) + 0.002).abs() < 1e-15);
+/// ```
+pub fn encode(value: f64) -> f64 {
+    let v = value.abs();
+    let e = if v <= 0.0031308 { v * 12.92 } else { 1.055 * v.powf(1.0 / 2.4) - 0.055 };
+    value.signum() * e
+}
+
+/// Decodes *sRGB* encoded values to linear light, this is the inverse of [`encode`].
+pub fn decode(value: f64) -> f64 {
+    let v = value.abs();
+    let l = if v <= 0.04045 { v / 12.92 } else { ((v + 0.055) / 1.055).powf(2.4) };
+    value.signum() * l
+}
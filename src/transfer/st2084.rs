@@ -0,0 +1,73 @@
+// Copyright © 2019 Xavier Basty <xavier@hexbee.net>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # SMPTE ST 2084 perceptual quantizer
+//!
+//! *EOTF* of the *PQ* high dynamic range system, also specified in *ITU-R BT.2100*.
+//!
+//! ## References
+//!
+//! [`SMPTE ST 2084:2014` : High Dynamic Range Electro-Optical Transfer Function of Mastering Reference Displays.](https://doi.org/10.5594/SMPTE.ST2084.2014)
+
+const M_1: f64 = 2610.0 / 4096.0 / 4.0;
+const M_2: f64 = 2523.0 / 4096.0 * 128.0;
+const C_1: f64 = 3424.0 / 4096.0;
+const C_2: f64 = 2413.0 / 4096.0 * 32.0;
+const C_3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// Peak luminance of the *PQ* signal range, in cd/m².
+pub const PEAK_LUMINANCE: f64 = 10000.0;
+
+/// Converts a non-linear *PQ* signal value to display luminance.
+///
+/// # Arguments
+///
+/// * `value` - non-linear *PQ* signal value, in [0, 1].
+/// * `l_p` - display peak luminance, in cd/m², usually [`PEAK_LUMINANCE`].
+///
+/// # Returns
+///
+/// Display luminance, in cd/m².
+///
+/// # Example
+///
+/// ```
+/// use pigments::transfer::st2084;
+///
+/// assert!((st2084::eotf(0.5080784, st2084::PEAK_LUMINANCE) - 100.0).abs() < 1e-4);
+/// assert!((st2084::eotf(1.0, st2084::PEAK_LUMINANCE) - 10000.0).abs() < 1e-9);
+/// assert!((st2084::eotf_inverse(100.0, st2084::PEAK_LUMINANCE) - 0.5080784).abs() < 1e-7);
+/// assert!((st2084::eotf(st2084::eotf_inverse(0.01, 10000.0), 10000.0) - 0.01).abs() < 1e-12);
+/// ```
+pub fn eotf(value: f64, l_p: f64) -> f64 {
+    let v = value.max(0.0).powf(1.0 / M_2);
+
+    l_p * ((v - C_1).max(0.0) / (C_2 - C_3 * v)).powf(1.0 / M_1)
+}
+
+/// Converts display luminance to a non-linear *PQ* signal value, this is the inverse of [`eotf`].
+///
+/// # Arguments
+///
+/// * `value` - display luminance, in cd/m².
+/// * `l_p` - display peak luminance, in cd/m², usually [`PEAK_LUMINANCE`].
+///
+/// # Returns
+///
+/// Non-linear *PQ* signal value.
+pub fn eotf_inverse(value: f64, l_p: f64) -> f64 {
+    let y = (value / l_p).max(0.0).powf(M_1);
+
+    ((C_1 + C_2 * y) / (1.0 + C_3 * y)).powf(M_2)
+}